@@ -1,4 +1,4 @@
-#[derive(Hash, PartialEq, Eq, Debug)]
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
 pub enum Element {
     // Period 1
     H,
@@ -252,6 +252,132 @@ impl Element {
     }
 }
 
+impl Element {
+    pub fn from_symbol(symbol: &str) -> Option<Element> {
+        match symbol {
+            "H" => Some(Element::H),
+            "He" => Some(Element::He),
+            "Li" => Some(Element::Li),
+            "Be" => Some(Element::Be),
+            "B" => Some(Element::B),
+            "C" => Some(Element::C),
+            "N" => Some(Element::N),
+            "O" => Some(Element::O),
+            "F" => Some(Element::F),
+            "Ne" => Some(Element::Ne),
+            "Na" => Some(Element::Na),
+            "Mg" => Some(Element::Mg),
+            "Al" => Some(Element::Al),
+            "Si" => Some(Element::Si),
+            "P" => Some(Element::P),
+            "S" => Some(Element::S),
+            "Cl" => Some(Element::Cl),
+            "Ar" => Some(Element::Ar),
+            "K" => Some(Element::K),
+            "Ca" => Some(Element::Ca),
+            "Sc" => Some(Element::Sc),
+            "Ti" => Some(Element::Ti),
+            "V" => Some(Element::V),
+            "Cr" => Some(Element::Cr),
+            "Mn" => Some(Element::Mn),
+            "Fe" => Some(Element::Fe),
+            "Co" => Some(Element::Co),
+            "Ni" => Some(Element::Ni),
+            "Cu" => Some(Element::Cu),
+            "Zn" => Some(Element::Zn),
+            "Ga" => Some(Element::Ga),
+            "Ge" => Some(Element::Ge),
+            "As" => Some(Element::As),
+            "Se" => Some(Element::Se),
+            "Br" => Some(Element::Br),
+            "Kr" => Some(Element::Kr),
+            "Rb" => Some(Element::Rb),
+            "Sr" => Some(Element::Sr),
+            "Y" => Some(Element::Y),
+            "Zr" => Some(Element::Zr),
+            "Nb" => Some(Element::Nb),
+            "Mo" => Some(Element::Mo),
+            "Tc" => Some(Element::Tc),
+            "Ru" => Some(Element::Ru),
+            "Rh" => Some(Element::Rh),
+            "Pd" => Some(Element::Pd),
+            "Ag" => Some(Element::Ag),
+            "Cd" => Some(Element::Cd),
+            "In" => Some(Element::In),
+            "Sn" => Some(Element::Sn),
+            "Sb" => Some(Element::Sb),
+            "Te" => Some(Element::Te),
+            "I" => Some(Element::I),
+            "Xe" => Some(Element::Xe),
+            "Cs" => Some(Element::Cs),
+            "Ba" => Some(Element::Ba),
+            "Hf" => Some(Element::Hf),
+            "Ta" => Some(Element::Ta),
+            "W" => Some(Element::W),
+            "Re" => Some(Element::Re),
+            "Os" => Some(Element::Os),
+            "Ir" => Some(Element::Ir),
+            "Pt" => Some(Element::Pt),
+            "Au" => Some(Element::Au),
+            "Hg" => Some(Element::Hg),
+            "Tl" => Some(Element::Tl),
+            "Pb" => Some(Element::Pb),
+            "Bi" => Some(Element::Bi),
+            "Po" => Some(Element::Po),
+            "At" => Some(Element::At),
+            "Rn" => Some(Element::Rn),
+            "Fr" => Some(Element::Fr),
+            "Ra" => Some(Element::Ra),
+            "Rf" => Some(Element::Rf),
+            "Db" => Some(Element::Db),
+            "Sg" => Some(Element::Sg),
+            "Bh" => Some(Element::Bh),
+            "Hs" => Some(Element::Hs),
+            "Mt" => Some(Element::Mt),
+            "Ds" => Some(Element::Ds),
+            "Rg" => Some(Element::Rg),
+            "Cn" => Some(Element::Cn),
+            "Nh" => Some(Element::Nh),
+            "Fl" => Some(Element::Fl),
+            "Mc" => Some(Element::Mc),
+            "Lv" => Some(Element::Lv),
+            "Ts" => Some(Element::Ts),
+            "Og" => Some(Element::Og),
+            "La" => Some(Element::La),
+            "Ce" => Some(Element::Ce),
+            "Pr" => Some(Element::Pr),
+            "Nd" => Some(Element::Nd),
+            "Pm" => Some(Element::Pm),
+            "Sm" => Some(Element::Sm),
+            "Eu" => Some(Element::Eu),
+            "Gd" => Some(Element::Gd),
+            "Tb" => Some(Element::Tb),
+            "Dy" => Some(Element::Dy),
+            "Ho" => Some(Element::Ho),
+            "Er" => Some(Element::Er),
+            "Tm" => Some(Element::Tm),
+            "Yb" => Some(Element::Yb),
+            "Lu" => Some(Element::Lu),
+            "Ac" => Some(Element::Ac),
+            "Th" => Some(Element::Th),
+            "Pa" => Some(Element::Pa),
+            "U" => Some(Element::U),
+            "Np" => Some(Element::Np),
+            "Pu" => Some(Element::Pu),
+            "Am" => Some(Element::Am),
+            "Cm" => Some(Element::Cm),
+            "Bk" => Some(Element::Bk),
+            "Cf" => Some(Element::Cf),
+            "Es" => Some(Element::Es),
+            "Fm" => Some(Element::Fm),
+            "Md" => Some(Element::Md),
+            "No" => Some(Element::No),
+            "Lr" => Some(Element::Lr),
+            _ => None,
+        }
+    }
+}
+
 impl Element {
     pub fn name(&self) -> &'static str {
         match self {
@@ -378,7 +504,7 @@ impl Element {
 }
 
 impl Element {
-    pub fn atomic_number(&self) -> u8 {
+    pub fn atomic_number(&self) -> u32 {
         match self {
             Element::H => 1,
             Element::He => 2,
@@ -515,7 +641,7 @@ impl Element {
 }
 
 impl Element {
-    pub fn atomic_mass(&self) -> f32 {
+    pub fn atomic_weight(&self) -> f64 {
         match self {
             Element::H => 1.008,
             Element::He => 4.0026,
@@ -650,3 +776,34 @@ impl Element {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn element_data() {
+        let cases = vec![
+            (Element::H, "H", 1, 1.008),
+            (Element::C, "C", 6, 12.011),
+            (Element::O, "O", 8, 15.999),
+            (Element::Na, "Na", 11, 22.990),
+            (Element::Cl, "Cl", 17, 35.45),
+            (Element::Fe, "Fe", 26, 55.845),
+            (Element::Kr, "Kr", 36, 83.798),
+        ];
+
+        for (element, symbol, atomic_number, atomic_weight) in cases {
+            assert_eq!(element.symbol(), symbol);
+            assert_eq!(element.atomic_number(), atomic_number);
+            assert!((element.atomic_weight() - atomic_weight).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn from_symbol() {
+        assert_eq!(Element::from_symbol("He"), Some(Element::He));
+        assert_eq!(Element::from_symbol("Og"), Some(Element::Og));
+        assert_eq!(Element::from_symbol("Xx"), None);
+    }
+}
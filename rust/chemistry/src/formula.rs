@@ -0,0 +1,176 @@
+use crate::element::Element;
+use crate::molecules::Molecule;
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Debug, PartialEq)]
+pub enum FormulaError {
+    Empty,
+    UnknownElement(String),
+    UnbalancedParentheses,
+    UnexpectedCharacter(char),
+    InvalidCount,
+}
+
+impl Molecule {
+    pub fn from_formula(s: &str) -> Result<Molecule, FormulaError> {
+        let mut chars = s.chars().peekable();
+
+        let counts = parse_sequence(&mut chars)?;
+        if chars.next().is_some() {
+            return Err(FormulaError::UnbalancedParentheses);
+        }
+        if counts.is_empty() {
+            return Err(FormulaError::Empty);
+        }
+
+        Ok(counts.into_iter().collect())
+    }
+}
+
+fn parse_sequence(chars: &mut Peekable<Chars>) -> Result<HashMap<Element, u32>, FormulaError> {
+    let mut counts = HashMap::new();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            'A'..='Z' => {
+                let element = parse_element(chars)?;
+                let count = parse_count(chars)?;
+                add_count(&mut counts, element, count)?;
+            }
+            '(' => {
+                chars.next();
+                let group = parse_sequence(chars)?;
+                if chars.next() != Some(')') {
+                    return Err(FormulaError::UnbalancedParentheses);
+                }
+                let multiplier = parse_count(chars)?;
+                for (element, count) in group {
+                    let count = count
+                        .checked_mul(multiplier)
+                        .ok_or(FormulaError::InvalidCount)?;
+                    add_count(&mut counts, element, count)?;
+                }
+            }
+            ')' => break,
+            _ => return Err(FormulaError::UnexpectedCharacter(c)),
+        }
+    }
+
+    Ok(counts)
+}
+
+fn add_count(
+    counts: &mut HashMap<Element, u32>,
+    element: Element,
+    count: u32,
+) -> Result<(), FormulaError> {
+    let total = counts.entry(element).or_insert(0);
+    *total = total.checked_add(count).ok_or(FormulaError::InvalidCount)?;
+
+    Ok(())
+}
+
+fn parse_element(chars: &mut Peekable<Chars>) -> Result<Element, FormulaError> {
+    let mut symbol = String::new();
+    if let Some(c) = chars.next() {
+        symbol.push(c);
+    }
+    if let Some(c) = chars.next_if(char::is_ascii_lowercase) {
+        symbol.push(c);
+    }
+
+    Element::from_symbol(&symbol).ok_or(FormulaError::UnknownElement(symbol))
+}
+
+fn parse_count(chars: &mut Peekable<Chars>) -> Result<u32, FormulaError> {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+
+    if digits.is_empty() {
+        return Ok(1);
+    }
+
+    // Explicit zero counts would leave an element present with no atoms
+    match digits.parse() {
+        Ok(0) | Err(_) => Err(FormulaError::InvalidCount),
+        Ok(count) => Ok(count),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn water() {
+        let expected: Molecule = [(Element::H, 2), (Element::O, 1)].into_iter().collect();
+
+        let result = Molecule::from_formula("H2O");
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn glucose() {
+        let expected: Molecule = [(Element::C, 6), (Element::H, 12), (Element::O, 6)]
+            .into_iter()
+            .collect();
+
+        let result = Molecule::from_formula("C6H12O6");
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn calcium_hydroxide() {
+        let expected: Molecule = [(Element::Ca, 1), (Element::O, 2), (Element::H, 2)]
+            .into_iter()
+            .collect();
+
+        let result = Molecule::from_formula("Ca(OH)2");
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn nested_groups() {
+        let expected: Molecule = [
+            (Element::K, 4),
+            (Element::Fe, 1),
+            (Element::C, 6),
+            (Element::N, 6),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = Molecule::from_formula("K4(Fe(CN)6)");
+
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn errors() {
+        let cases = vec![
+            ("Xy2", FormulaError::UnknownElement(String::from("Xy"))),
+            ("Ca(OH2", FormulaError::UnbalancedParentheses),
+            ("CaOH)2", FormulaError::UnbalancedParentheses),
+            ("H2 O", FormulaError::UnexpectedCharacter(' ')),
+            ("", FormulaError::Empty),
+            ("H0", FormulaError::InvalidCount),
+            ("Ca(OH)0", FormulaError::InvalidCount),
+            ("H99999999999", FormulaError::InvalidCount),
+            ("(H100000)100000", FormulaError::InvalidCount),
+            ("H4294967295H", FormulaError::InvalidCount),
+        ];
+
+        for (formula, expected) in cases {
+            let result = Molecule::from_formula(formula);
+
+            assert_eq!(result, Err(expected), "{} should fail", formula);
+        }
+    }
+}
@@ -1,2 +1,4 @@
-mod element;
-mod molecules;
+pub mod element;
+pub mod formula;
+pub mod molecules;
+pub mod reaction;
@@ -1,13 +1,78 @@
 use crate::element::Element;
 use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, PartialEq)]
+pub enum MoleculeError {
+    InsufficientAtoms(Element),
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct Molecule(pub HashMap<Element, u32>);
 
 impl Molecule {
     pub fn new() -> Self {
         Molecule(HashMap::new())
     }
+
+    pub fn molar_mass(&self) -> f64 {
+        self.0
+            .iter()
+            .map(|(element, count)| element.atomic_weight() * *count as f64)
+            .sum()
+    }
+
+    pub fn percent_composition(&self) -> HashMap<Element, f64> {
+        let total = self.molar_mass();
+
+        self.0
+            .iter()
+            .map(|(element, count)| {
+                let mass = element.atomic_weight() * *count as f64;
+                (*element, 100.0 * mass / total)
+            })
+            .collect()
+    }
+
+    pub fn combine(&self, other: &Molecule) -> Molecule {
+        self.0
+            .iter()
+            .chain(other.0.iter())
+            .map(|(element, count)| (*element, *count))
+            .collect()
+    }
+
+    pub fn try_subtract(&self, other: &Molecule) -> Result<Molecule, MoleculeError> {
+        let mut map = self.0.clone();
+
+        for (element, count) in &other.0 {
+            let remaining = map.get(element).copied().unwrap_or(0);
+            if remaining < *count {
+                return Err(MoleculeError::InsufficientAtoms(*element));
+            }
+            if remaining == *count {
+                map.remove(element);
+            } else {
+                map.insert(*element, remaining - count);
+            }
+        }
+
+        Ok(Molecule(map))
+    }
+
+    pub fn empirical_formula(&self) -> Molecule {
+        let divisor = self.0.values().copied().fold(0, gcd).max(1);
+
+        self.0
+            .iter()
+            .map(|(element, count)| (*element, count / divisor))
+            .collect()
+    }
+}
+
+pub(crate) fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
 }
 
 impl FromIterator<(Element, u32)> for Molecule {
@@ -22,16 +87,46 @@ impl FromIterator<(Element, u32)> for Molecule {
     }
 }
 
+// The map has no stable iteration order, so hash the counts sorted by atomic number
+impl Hash for Molecule {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut entries: Vec<(u32, u32)> = self
+            .0
+            .iter()
+            .map(|(element, count)| (element.atomic_number(), *count))
+            .collect();
+        entries.sort();
+        entries.hash(state);
+    }
+}
+
+impl fmt::Display for Molecule {
+    // Hill notation: carbon then hydrogen when carbon is present, everything else alphabetical
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let has_carbon = self.0.contains_key(&Element::C);
+        let mut entries: Vec<(&Element, &u32)> = self.0.iter().collect();
+        entries.sort_by_key(|(element, _)| match element {
+            Element::C if has_carbon => (0, ""),
+            Element::H if has_carbon => (1, ""),
+            _ => (2, element.symbol()),
+        });
+
+        for (element, count) in entries {
+            write!(f, "{}", element.symbol())?;
+            if *count != 1 {
+                write!(f, "{}", count)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 pub struct Compound {
     molecule: Molecule,
     charge: i32,
 }
 
-pub struct Reaction {
-    reactants: HashMap<Molecule, u32>,
-    products: HashMap<Molecule, u32>,
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -42,4 +137,133 @@ mod test {
         let sodium: Molecule = [(Element::Na, 1)].into_iter().collect();
         assert_ne!(chlorine, sodium);
     }
+
+    #[test]
+    fn molar_mass() {
+        let cases = vec![
+            (Molecule::from_formula("H2O").unwrap(), 18.015),
+            (Molecule::from_formula("CO2").unwrap(), 44.009),
+            (Molecule::from_formula("C6H12O6").unwrap(), 180.156),
+        ];
+
+        for (molecule, expected) in cases {
+            let result = molecule.molar_mass();
+
+            assert!(
+                (result - expected).abs() < 0.001,
+                "{} should be {}",
+                result,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn display_hill_notation() {
+        let cases = vec![
+            ("C6H12O6", "C6H12O6"),
+            ("H2O", "H2O"),
+            ("OH2", "H2O"),
+            ("CH3CH2OH", "C2H6O"),
+            ("NaCl", "ClNa"),
+            ("Ca(OH)2", "CaH2O2"),
+            ("CO2", "CO2"),
+        ];
+
+        for (formula, expected) in cases {
+            let molecule = Molecule::from_formula(formula).unwrap();
+
+            assert_eq!(molecule.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn combine() {
+        let water = Molecule::from_formula("H2O").unwrap();
+        let carbon_dioxide = Molecule::from_formula("CO2").unwrap();
+
+        let result = water.combine(&carbon_dioxide);
+
+        assert_eq!(result, Molecule::from_formula("CH2O3").unwrap());
+    }
+
+    #[test]
+    fn try_subtract() {
+        let carbonic_acid = Molecule::from_formula("H2CO3").unwrap();
+        let water = Molecule::from_formula("H2O").unwrap();
+
+        let result = carbonic_acid.try_subtract(&water);
+
+        assert_eq!(result, Ok(Molecule::from_formula("CO2").unwrap()));
+    }
+
+    #[test]
+    fn try_subtract_too_many_atoms() {
+        let water = Molecule::from_formula("H2O").unwrap();
+        let peroxide = Molecule::from_formula("H2O2").unwrap();
+        let nitrogen = Molecule::from_formula("N2").unwrap();
+
+        assert_eq!(
+            water.try_subtract(&peroxide),
+            Err(MoleculeError::InsufficientAtoms(Element::O))
+        );
+        assert_eq!(
+            water.try_subtract(&nitrogen),
+            Err(MoleculeError::InsufficientAtoms(Element::N))
+        );
+    }
+
+    #[test]
+    fn percent_composition() {
+        let water = Molecule::from_formula("H2O").unwrap();
+
+        let result = water.percent_composition();
+
+        assert!((result[&Element::H] - 11.19).abs() < 0.01);
+        assert!((result[&Element::O] - 88.81).abs() < 0.01);
+    }
+
+    #[test]
+    fn percent_composition_sums_to_100() {
+        let glucose = Molecule::from_formula("C6H12O6").unwrap();
+
+        let total: f64 = glucose.percent_composition().values().sum();
+
+        assert!((total - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn equal_molecules_share_a_map_key() {
+        let glucose: Molecule = [(Element::C, 6), (Element::H, 12), (Element::O, 6)]
+            .into_iter()
+            .collect();
+        let reordered: Molecule = [(Element::O, 6), (Element::C, 6), (Element::H, 12)]
+            .into_iter()
+            .collect();
+        let mut counts: HashMap<Molecule, u32> = HashMap::new();
+
+        *counts.entry(glucose).or_insert(0) += 1;
+        *counts.entry(reordered).or_insert(0) += 1;
+
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts.values().next(), Some(&2));
+    }
+
+    #[test]
+    fn empirical_formula() {
+        let cases = vec![
+            ("C6H12O6", "CH2O"),
+            ("H2O2", "HO"),
+            ("H2O", "H2O"),
+            ("C2H4", "CH2"),
+        ];
+
+        for (formula, expected) in cases {
+            let molecule = Molecule::from_formula(formula).unwrap();
+
+            let result = molecule.empirical_formula();
+
+            assert_eq!(result, Molecule::from_formula(expected).unwrap());
+        }
+    }
 }
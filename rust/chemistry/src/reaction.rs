@@ -0,0 +1,401 @@
+use crate::element::Element;
+use crate::molecules::{Molecule, gcd};
+use maths::matrix::Matrix;
+use std::collections::HashMap;
+use std::fmt;
+
+const MAX_COEFFICIENT_MULTIPLIER: u32 = 10_000;
+const INTEGER_EPSILON: f64 = 1e-6;
+const STOICHIOMETRIC_EPSILON: f64 = 1e-9;
+
+#[derive(Debug, PartialEq)]
+pub enum BalanceError {
+    NoSolution,
+    MultipleSolutions,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Reaction {
+    reactants: HashMap<Molecule, u32>,
+    products: HashMap<Molecule, u32>,
+}
+
+impl Reaction {
+    pub fn new(reactants: HashMap<Molecule, u32>, products: HashMap<Molecule, u32>) -> Self {
+        Reaction {
+            reactants,
+            products,
+        }
+    }
+
+    pub fn reactants(&self) -> &HashMap<Molecule, u32> {
+        &self.reactants
+    }
+
+    pub fn products(&self) -> &HashMap<Molecule, u32> {
+        &self.products
+    }
+
+    /// Checks every element is conserved using the stored coefficients. Reaction terms are
+    /// neutral molecules, so charge is always conserved. Totals too large to count are treated
+    /// as unbalanced.
+    pub fn is_balanced(&self) -> bool {
+        match (atom_totals(&self.reactants), atom_totals(&self.products)) {
+            (Some(reactants), Some(products)) => reactants == products,
+            _ => false,
+        }
+    }
+
+    /// Returns the reactant that runs out first given the amount of each (in moles), or
+    /// `None` when the reaction is unbalanced or the amounts are exactly stoichiometric.
+    /// Reactants missing from `amounts` are treated as having none available.
+    pub fn limiting_reactant(&self, amounts: &HashMap<Molecule, f64>) -> Option<Molecule> {
+        if !self.is_balanced() {
+            return None;
+        }
+
+        let extents: Vec<(&Molecule, f64)> = self
+            .reactants
+            .iter()
+            .map(|(molecule, coefficient)| {
+                let amount = amounts.get(molecule).copied().unwrap_or(0.0);
+                (molecule, amount / *coefficient as f64)
+            })
+            .collect();
+
+        let (limiting, smallest) = extents.iter().min_by(|a, b| a.1.total_cmp(&b.1)).copied()?;
+        if extents
+            .iter()
+            .all(|(_, extent)| (extent - smallest).abs() < STOICHIOMETRIC_EPSILON)
+        {
+            return None;
+        }
+
+        Some(limiting.clone())
+    }
+
+    /// Finds the smallest positive integer coefficients by taking the null space of the
+    /// element composition matrix, ignoring any coefficients already on the reaction.
+    pub fn balance(&self) -> Result<Reaction, BalanceError> {
+        let reactants: Vec<&Molecule> = self.reactants.keys().collect();
+        let products: Vec<&Molecule> = self.products.keys().collect();
+        let molecules: Vec<(&Molecule, f64)> = reactants
+            .iter()
+            .map(|m| (*m, 1.0))
+            .chain(products.iter().map(|m| (*m, -1.0)))
+            .collect();
+
+        let mut elements: Vec<Element> = molecules
+            .iter()
+            .flat_map(|(m, _)| m.0.keys().copied())
+            .collect();
+        elements.sort_by_key(|e| e.atomic_number());
+        elements.dedup();
+
+        let mut data = Vec::with_capacity(elements.len() * molecules.len());
+        for element in &elements {
+            for (molecule, sign) in &molecules {
+                let count = molecule.0.get(element).copied().unwrap_or(0);
+                data.push(sign * count as f64);
+            }
+        }
+        let composition =
+            Matrix::new(elements.len(), molecules.len(), data).expect("data sized to fit");
+        let reduced = composition.rref();
+
+        let pivots: Vec<usize> = (0..reduced.rows)
+            .filter_map(|row| {
+                (0..reduced.columns).find(|c| reduced.get(row, *c).expect("in range") != 0.0)
+            })
+            .collect();
+        let free: Vec<usize> = (0..reduced.columns)
+            .filter(|c| !pivots.contains(c))
+            .collect();
+        let free = match free.len() {
+            0 => return Err(BalanceError::NoSolution),
+            1 => free[0],
+            _ => return Err(BalanceError::MultipleSolutions),
+        };
+
+        let mut solution = vec![0.0; molecules.len()];
+        solution[free] = 1.0;
+        for (row, pivot) in pivots.iter().enumerate() {
+            solution[*pivot] = -reduced.get(row, free).expect("in range");
+        }
+
+        let coefficients = smallest_integers(&solution).ok_or(BalanceError::NoSolution)?;
+        let (reactant_coefficients, product_coefficients) = coefficients.split_at(reactants.len());
+
+        Ok(Reaction {
+            reactants: reactants
+                .into_iter()
+                .cloned()
+                .zip(reactant_coefficients.iter().copied())
+                .collect(),
+            products: products
+                .into_iter()
+                .cloned()
+                .zip(product_coefficients.iter().copied())
+                .collect(),
+        })
+    }
+}
+
+// Accumulates in u64 so a u32 count times a u32 coefficient always fits; `None` on overflow
+fn atom_totals(side: &HashMap<Molecule, u32>) -> Option<HashMap<Element, u64>> {
+    let mut totals = HashMap::new();
+
+    for (molecule, coefficient) in side {
+        for (element, count) in &molecule.0 {
+            let total = totals.entry(*element).or_insert(0u64);
+            *total = total.checked_add(u64::from(*count) * u64::from(*coefficient))?;
+        }
+    }
+
+    Some(totals)
+}
+
+impl fmt::Display for Reaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_side(f, &self.reactants)?;
+        write!(f, " -> ")?;
+        write_side(f, &self.products)
+    }
+}
+
+// Sorted by formula so the output doesn't depend on map iteration order
+fn write_side(f: &mut fmt::Formatter, side: &HashMap<Molecule, u32>) -> fmt::Result {
+    let mut terms: Vec<(String, u32)> = side
+        .iter()
+        .map(|(molecule, coefficient)| (molecule.to_string(), *coefficient))
+        .collect();
+    terms.sort();
+
+    for (i, (formula, coefficient)) in terms.iter().enumerate() {
+        if i > 0 {
+            write!(f, " + ")?;
+        }
+        if *coefficient > 1 {
+            write!(f, "{}", coefficient)?;
+        }
+        write!(f, "{}", formula)?;
+    }
+
+    Ok(())
+}
+
+fn smallest_integers(values: &[f64]) -> Option<Vec<u32>> {
+    if values.iter().any(|v| *v <= 0.0) {
+        return None;
+    }
+
+    let smallest = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let scaled: Vec<f64> = values.iter().map(|v| v / smallest).collect();
+
+    let multiplier = (1..=MAX_COEFFICIENT_MULTIPLIER).find(|k| {
+        scaled
+            .iter()
+            .all(|v| (v * *k as f64 - (v * *k as f64).round()).abs() < INTEGER_EPSILON)
+    })?;
+    let integers: Vec<u32> = scaled
+        .iter()
+        .map(|v| (v * multiplier as f64).round() as u32)
+        .collect();
+
+    let divisor = integers.iter().copied().fold(0, gcd);
+    Some(integers.iter().map(|v| v / divisor).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn side(molecules: &[(&str, u32)]) -> HashMap<Molecule, u32> {
+        molecules
+            .iter()
+            .map(|(formula, count)| (Molecule::from_formula(formula).unwrap(), *count))
+            .collect()
+    }
+
+    #[test]
+    fn balance_water() {
+        let reaction = Reaction::new(side(&[("H2", 1), ("O2", 1)]), side(&[("H2O", 1)]));
+
+        let result = reaction.balance();
+
+        let expected = Reaction::new(side(&[("H2", 2), ("O2", 1)]), side(&[("H2O", 2)]));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn balance_methane_combustion() {
+        let reaction = Reaction::new(
+            side(&[("CH4", 1), ("O2", 1)]),
+            side(&[("CO2", 1), ("H2O", 1)]),
+        );
+
+        let result = reaction.balance();
+
+        let expected = Reaction::new(
+            side(&[("CH4", 1), ("O2", 2)]),
+            side(&[("CO2", 1), ("H2O", 2)]),
+        );
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn balance_ignores_existing_coefficients() {
+        let reaction = Reaction::new(
+            side(&[("C3H8", 3), ("O2", 1)]),
+            side(&[("CO2", 7), ("H2O", 1)]),
+        );
+
+        let result = reaction.balance();
+
+        let expected = Reaction::new(
+            side(&[("C3H8", 1), ("O2", 5)]),
+            side(&[("CO2", 3), ("H2O", 4)]),
+        );
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn balance_errors() {
+        let cases = vec![
+            (
+                Reaction::new(side(&[("H2", 1)]), side(&[("O2", 1)])),
+                BalanceError::NoSolution,
+            ),
+            (
+                Reaction::new(
+                    side(&[("H2", 1), ("O2", 1)]),
+                    side(&[("H2O", 1), ("H2O2", 1)]),
+                ),
+                BalanceError::MultipleSolutions,
+            ),
+        ];
+
+        for (reaction, expected) in cases {
+            let result = reaction.balance();
+
+            assert_eq!(result, Err(expected));
+        }
+    }
+
+    #[test]
+    fn display() {
+        let cases = vec![
+            (
+                Reaction::new(side(&[("H2", 2), ("O2", 1)]), side(&[("H2O", 2)])),
+                "2H2 + O2 -> 2H2O",
+            ),
+            (
+                Reaction::new(
+                    side(&[("O2", 2), ("CH4", 1)]),
+                    side(&[("H2O", 2), ("CO2", 1)]),
+                ),
+                "CH4 + 2O2 -> CO2 + 2H2O",
+            ),
+        ];
+
+        for (reaction, expected) in cases {
+            assert_eq!(reaction.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn is_balanced() {
+        let cases = vec![
+            (
+                Reaction::new(side(&[("H2", 2), ("O2", 1)]), side(&[("H2O", 2)])),
+                true,
+            ),
+            (
+                Reaction::new(
+                    side(&[("CH4", 1), ("O2", 2)]),
+                    side(&[("CO2", 1), ("H2O", 2)]),
+                ),
+                true,
+            ),
+            (
+                Reaction::new(side(&[("H2", 1), ("O2", 1)]), side(&[("H2O", 1)])),
+                false,
+            ),
+            (
+                Reaction::new(side(&[("H2", 2), ("O2", 1)]), side(&[("H2O2", 2)])),
+                false,
+            ),
+        ];
+
+        for (reaction, expected) in cases {
+            assert_eq!(reaction.is_balanced(), expected, "{}", reaction);
+        }
+    }
+
+    #[test]
+    fn is_balanced_large_coefficients() {
+        let hydrogen: Molecule = [(Element::H, 2)].into_iter().collect();
+        let peroxide: Molecule = [(Element::H, 4)].into_iter().collect();
+        let huge: Molecule = [(Element::H, u32::MAX)].into_iter().collect();
+        let huge_hydroxide: Molecule = [(Element::H, u32::MAX), (Element::O, 1)]
+            .into_iter()
+            .collect();
+
+        let same = |m: &Molecule| HashMap::from([(m.clone(), u32::MAX)]);
+        let overflowing =
+            || HashMap::from([(huge.clone(), u32::MAX), (huge_hydroxide.clone(), u32::MAX)]);
+
+        assert!(Reaction::new(same(&hydrogen), same(&hydrogen)).is_balanced());
+        assert!(
+            !Reaction::new(same(&hydrogen), HashMap::from([(peroxide, u32::MAX / 2)]))
+                .is_balanced()
+        );
+        assert!(!Reaction::new(overflowing(), overflowing()).is_balanced());
+    }
+
+    #[test]
+    fn balanced_reaction_is_balanced() {
+        let reaction = Reaction::new(
+            side(&[("C3H8", 1), ("O2", 1)]),
+            side(&[("CO2", 1), ("H2O", 1)]),
+        );
+
+        assert!(reaction.balance().unwrap().is_balanced());
+    }
+
+    #[test]
+    fn limiting_reactant() {
+        let reaction = Reaction::new(side(&[("H2", 2), ("O2", 1)]), side(&[("H2O", 2)]));
+        let hydrogen = Molecule::from_formula("H2").unwrap();
+        let oxygen = Molecule::from_formula("O2").unwrap();
+
+        let cases = vec![
+            (vec![(&hydrogen, 3.0), (&oxygen, 2.0)], Some(&hydrogen)),
+            (vec![(&hydrogen, 5.0), (&oxygen, 2.0)], Some(&oxygen)),
+            (vec![(&hydrogen, 4.0), (&oxygen, 2.0)], None),
+            (vec![(&hydrogen, 4.0)], Some(&oxygen)),
+        ];
+
+        for (amounts, expected) in cases {
+            let amounts: HashMap<Molecule, f64> = amounts
+                .into_iter()
+                .map(|(molecule, amount)| (molecule.clone(), amount))
+                .collect();
+
+            let result = reaction.limiting_reactant(&amounts);
+
+            assert_eq!(result.as_ref(), expected);
+        }
+    }
+
+    #[test]
+    fn limiting_reactant_requires_balanced_reaction() {
+        let reaction = Reaction::new(side(&[("H2", 1), ("O2", 1)]), side(&[("H2O", 1)]));
+        let amounts = side(&[("H2", 1), ("O2", 5)])
+            .into_iter()
+            .map(|(molecule, amount)| (molecule, amount as f64))
+            .collect();
+
+        assert_eq!(reaction.limiting_reactant(&amounts), None);
+    }
+}
@@ -0,0 +1,19 @@
+use super::Color;
+use maths::approx_eq::ApproxEq;
+
+impl ApproxEq for Color {
+    fn approx_eq(&self, other: &Color, eps: f64) -> bool {
+        self.r.approx_eq(&other.r, eps)
+            && self.g.approx_eq(&other.g, eps)
+            && self.b.approx_eq(&other.b, eps)
+            && self.a.approx_eq(&other.a, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.r
+            .abs()
+            .max(self.g.abs())
+            .max(self.b.abs())
+            .max(self.a.abs())
+    }
+}
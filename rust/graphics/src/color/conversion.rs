@@ -0,0 +1,107 @@
+use super::Color;
+
+impl Color {
+    /// `h` is in degrees and wraps around; `s` and `v` are in `[0, 1]`. Alpha is opaque.
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Color {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let chroma = v * s;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = v - chroma;
+
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Color::rgb(r + m, g + m, b + m)
+    }
+
+    /// Returns `(h, s, v)` with `h` in `[0, 360)`. Greys have no defined hue, so report 0;
+    /// black likewise reports a saturation of 0.
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+
+        let h = if chroma == 0.0 {
+            0.0
+        } else if max == self.r {
+            60.0 * ((self.g - self.b) / chroma).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / chroma + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / chroma + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { chroma / max };
+
+        (h, s, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use maths::approx_eq::ApproxEq;
+
+    #[test]
+    fn from_hsv() {
+        struct HsvCase {
+            hsv: (f64, f64, f64),
+            expected: Color,
+        }
+
+        let cases: Vec<HsvCase> = vec![
+            HsvCase {
+                hsv: (0.0, 1.0, 1.0),
+                expected: Color::rgb(1.0, 0.0, 0.0),
+            },
+            HsvCase {
+                hsv: (120.0, 1.0, 1.0),
+                expected: Color::rgb(0.0, 1.0, 0.0),
+            },
+            HsvCase {
+                hsv: (600.0, 1.0, 0.5),
+                expected: Color::rgb(0.0, 0.0, 0.5),
+            },
+            HsvCase {
+                hsv: (200.0, 0.0, 0.3),
+                expected: Color::rgb(0.3, 0.3, 0.3),
+            },
+        ];
+
+        for case in cases {
+            let (h, s, v) = case.hsv;
+
+            assert!(Color::from_hsv(h, s, v).approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        let cases: Vec<Color> = vec![
+            Color::rgb(1.0, 0.0, 0.0),
+            Color::rgb(0.2, 0.6, 0.4),
+            Color::rgb(0.9, 0.1, 0.7),
+            Color::rgb(0.25, 0.5, 1.0),
+            Color::rgb(0.0, 0.0, 0.0),
+        ];
+
+        for case in cases {
+            let (h, s, v) = case.to_hsv();
+
+            assert!(Color::from_hsv(h, s, v).approx_eq_default(&case));
+        }
+    }
+
+    #[test]
+    fn achromatic_hue() {
+        let (h, s, v) = Color::rgb(0.4, 0.4, 0.4).to_hsv();
+
+        assert_eq!((h, s), (0.0, 0.0));
+        assert!(v.approx_eq_default(&0.4));
+    }
+}
@@ -0,0 +1,120 @@
+use super::Color;
+
+impl Color {
+    pub fn new(r: f64, g: f64, b: f64, a: f64) -> Color {
+        Color { r, g, b, a }
+    }
+
+    pub fn rgb(r: f64, g: f64, b: f64) -> Color {
+        Color::new(r, g, b, 1.0)
+    }
+
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    pub fn g(&self) -> f64 {
+        self.g
+    }
+
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Interpolates every channel, alpha included; `t` is not clamped.
+    pub fn lerp(&self, other: &Color, t: f64) -> Color {
+        Color::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+            self.a + (other.a - self.a) * t,
+        )
+    }
+
+    /// Channel-wise product, as used for tinting and lighting.
+    pub fn multiply(&self, other: &Color) -> Color {
+        Color::new(
+            self.r * other.r,
+            self.g * other.g,
+            self.b * other.b,
+            self.a * other.a,
+        )
+    }
+
+    /// Channel-wise sum; the result may leave `[0, 1]` until clamped.
+    pub fn add(&self, other: &Color) -> Color {
+        Color::new(
+            self.r + other.r,
+            self.g + other.g,
+            self.b + other.b,
+            self.a + other.a,
+        )
+    }
+
+    pub fn clamp01(&self) -> Color {
+        Color::new(
+            self.r.clamp(0.0, 1.0),
+            self.g.clamp(0.0, 1.0),
+            self.b.clamp(0.0, 1.0),
+            self.a.clamp(0.0, 1.0),
+        )
+    }
+
+    pub fn to_u8_rgba(&self) -> [u8; 4] {
+        let c = self.clamp01();
+
+        [c.r, c.g, c.b, c.a].map(|v| (v * 255.0).round() as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use maths::approx_eq::ApproxEq;
+
+    #[test]
+    fn lerp() {
+        let black = Color::rgb(0.0, 0.0, 0.0);
+        let white = Color::rgb(1.0, 1.0, 1.0);
+
+        assert!(
+            black
+                .lerp(&white, 0.5)
+                .approx_eq_default(&Color::rgb(0.5, 0.5, 0.5))
+        );
+        assert!(black.lerp(&white, 0.0).approx_eq_default(&black));
+        assert!(black.lerp(&white, 1.0).approx_eq_default(&white));
+    }
+
+    #[test]
+    fn multiply_and_add() {
+        let a = Color::new(0.5, 1.0, 0.2, 1.0);
+        let b = Color::new(0.5, 0.5, 1.0, 0.5);
+
+        assert!(
+            a.multiply(&b)
+                .approx_eq_default(&Color::new(0.25, 0.5, 0.2, 0.5))
+        );
+        assert!(a.add(&b).approx_eq_default(&Color::new(1.0, 1.5, 1.2, 1.5)));
+    }
+
+    #[test]
+    fn clamp01() {
+        let result = Color::new(-0.5, 0.25, 1.5, 2.0).clamp01();
+
+        assert!(result.approx_eq_default(&Color::new(0.0, 0.25, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn to_u8_rgba() {
+        assert_eq!(Color::rgb(1.0, 0.0, 0.5).to_u8_rgba(), [255, 0, 128, 255]);
+        assert_eq!(
+            Color::new(2.0, -1.0, 0.2, 0.0).to_u8_rgba(),
+            [255, 0, 51, 0]
+        );
+    }
+}
@@ -0,0 +1,12 @@
+mod approx_eq;
+mod conversion;
+mod core;
+
+/// Linear RGBA with each channel nominally in `[0, 1]`.
+#[derive(PartialEq, Debug)]
+pub struct Color {
+    r: f64,
+    g: f64,
+    b: f64,
+    a: f64,
+}
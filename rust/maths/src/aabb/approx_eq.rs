@@ -0,0 +1,12 @@
+use super::Aabb;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Aabb {
+    fn approx_eq(&self, other: &Aabb, eps: f64) -> bool {
+        self.min.approx_eq(&other.min, eps) && self.max.approx_eq(&other.max, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.min.max_abs().max(self.max.max_abs())
+    }
+}
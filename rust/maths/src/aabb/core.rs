@@ -0,0 +1,202 @@
+use super::Aabb;
+use crate::ray::Ray;
+use crate::vector3::Vector3;
+
+impl Aabb {
+    /// Accepts any two opposite corners, sorting them per component into `min` and `max`.
+    pub fn new(a: &Vector3, b: &Vector3) -> Aabb {
+        Aabb {
+            min: Vector3::new(a.x().min(b.x()), a.y().min(b.y()), a.z().min(b.z())),
+            max: Vector3::new(a.x().max(b.x()), a.y().max(b.y()), a.z().max(b.z())),
+        }
+    }
+
+    pub fn min(&self) -> &Vector3 {
+        &self.min
+    }
+
+    pub fn max(&self) -> &Vector3 {
+        &self.max
+    }
+
+    pub fn contains(&self, p: &Vector3) -> bool {
+        p.x() >= self.min.x()
+            && p.x() <= self.max.x()
+            && p.y() >= self.min.y()
+            && p.y() <= self.max.y()
+            && p.z() >= self.min.z()
+            && p.z() <= self.max.z()
+    }
+
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x() <= other.max.x()
+            && self.max.x() >= other.min.x()
+            && self.min.y() <= other.max.y()
+            && self.max.y() >= other.min.y()
+            && self.min.z() <= other.max.z()
+            && self.max.z() >= other.min.z()
+    }
+
+    pub fn merge(&self, other: &Aabb) -> Aabb {
+        Aabb::new(
+            &Vector3::new(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+                self.min.z().min(other.min.z()),
+            ),
+            &Vector3::new(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+                self.max.z().max(other.max.z()),
+            ),
+        )
+    }
+
+    /// Slab method. Returns `(entry, exit)` along the ray, with entry clamped to zero when the
+    /// origin starts inside the box.
+    pub fn ray_intersect(&self, ray: &Ray) -> Option<(f64, f64)> {
+        let origin = ray.origin().to_array();
+        let direction = ray.direction().to_array();
+        let min = self.min.to_array();
+        let max = self.max.to_array();
+
+        let mut entry: f64 = 0.0;
+        let mut exit = f64::INFINITY;
+        for axis in 0..3 {
+            if direction[axis] == 0.0 {
+                if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            let near = (min[axis] - origin[axis]) / direction[axis];
+            let far = (max[axis] - origin[axis]) / direction[axis];
+            entry = entry.max(near.min(far));
+            exit = exit.min(near.max(far));
+
+            if entry > exit {
+                return None;
+            }
+        }
+
+        Some((entry, exit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    fn unit_box() -> Aabb {
+        Aabb::new(&Vector3::new(0, 0, 0), &Vector3::new(1, 1, 1))
+    }
+
+    #[test]
+    fn new_sorts_corners() {
+        let result = Aabb::new(&Vector3::new(3, -1, 2), &Vector3::new(1, 4, -2));
+
+        assert!(result.min().approx_eq_default(&Vector3::new(1, -1, -2)));
+        assert!(result.max().approx_eq_default(&Vector3::new(3, 4, 2)));
+    }
+
+    #[test]
+    fn contains() {
+        let cases: Vec<(Vector3, bool)> = vec![
+            (Vector3::new(0.5, 0.5, 0.5), true),
+            (Vector3::new(0, 0, 0), true),
+            (Vector3::new(1, 1, 1), true),
+            (Vector3::new(1.1, 0.5, 0.5), false),
+            (Vector3::new(0.5, -0.1, 0.5), false),
+            (Vector3::new(0.5, 0.5, 2), false),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(unit_box().contains(&point), expected, "{}", point);
+        }
+    }
+
+    #[test]
+    fn intersects() {
+        let cases: Vec<(Aabb, bool)> = vec![
+            (
+                Aabb::new(&Vector3::new(0.5, 0.5, 0.5), &Vector3::new(2, 2, 2)),
+                true,
+            ),
+            (
+                Aabb::new(&Vector3::new(1, 0, 0), &Vector3::new(2, 1, 1)),
+                true,
+            ),
+            (
+                Aabb::new(&Vector3::new(0.2, 0.2, 0.2), &Vector3::new(0.8, 0.8, 0.8)),
+                true,
+            ),
+            (
+                Aabb::new(&Vector3::new(2, 2, 2), &Vector3::new(3, 3, 3)),
+                false,
+            ),
+            (
+                Aabb::new(&Vector3::new(0, 0, 1.5), &Vector3::new(1, 1, 2)),
+                false,
+            ),
+        ];
+
+        for (other, expected) in cases {
+            assert_eq!(unit_box().intersects(&other), expected, "{}", other);
+            assert_eq!(other.intersects(&unit_box()), expected, "{}", other);
+        }
+    }
+
+    #[test]
+    fn merge() {
+        let other = Aabb::new(&Vector3::new(-1, 0.5, 0.2), &Vector3::new(0.5, 3, 0.4));
+
+        let result = unit_box().merge(&other);
+
+        let expected = Aabb::new(&Vector3::new(-1, 0, 0), &Vector3::new(1, 3, 1));
+        assert!(result.approx_eq_default(&expected));
+        assert!(result.contains(&Vector3::new(-1, 3, 0)));
+    }
+
+    #[test]
+    fn ray_intersect() {
+        struct IntersectCase {
+            ray: Ray,
+            expected: Option<(f64, f64)>,
+        }
+
+        let cases: Vec<IntersectCase> = vec![
+            IntersectCase {
+                ray: Ray::new(Vector3::new(-2, 0.5, 0.5), Vector3::new(1, 0, 0)).unwrap(),
+                expected: Some((2.0, 3.0)),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(-2, 2, 0.5), Vector3::new(1, 0, 0)).unwrap(),
+                expected: None,
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(0, 0, 1)).unwrap(),
+                expected: Some((0.0, 0.5)),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(-1, -1, 0.5), Vector3::new(1, 1, 0)).unwrap(),
+                expected: Some((2f64.sqrt(), 8f64.sqrt())),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(3, 0.5, 0.5), Vector3::new(1, 0, 0)).unwrap(),
+                expected: None,
+            },
+        ];
+
+        for case in cases {
+            let result = unit_box().ray_intersect(&case.ray);
+
+            match (result, case.expected) {
+                (Some(t), Some(expected)) => assert!(t.approx_eq_default(&expected)),
+                (None, None) => {}
+                _ => panic!("{} expected {:?} got {:?}", case.ray, case.expected, result),
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use super::Aabb;
+use std::fmt;
+
+impl fmt::Display for Aabb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} - {}]", self.min, self.max)
+    }
+}
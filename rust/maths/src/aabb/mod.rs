@@ -0,0 +1,11 @@
+mod approx_eq;
+mod core;
+mod display;
+
+use crate::vector3::Vector3;
+
+#[derive(PartialEq, Debug)]
+pub struct Aabb {
+    min: Vector3,
+    max: Vector3,
+}
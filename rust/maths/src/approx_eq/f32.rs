@@ -0,0 +1,35 @@
+use super::ApproxEq;
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, b: &f32, eps: f64) -> bool {
+        (self - b).abs() as f64 <= eps
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.abs() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_eq() {
+        let a: f32 = 0.1 + 0.2;
+        let b: f32 = 0.3;
+
+        assert!(a.approx_eq(&b, f32::EPSILON as f64));
+        assert!(!1.0_f32.approx_eq(&1.001, 1e-4));
+        assert!(1.0_f32.approx_eq_default(&1.0000001));
+    }
+
+    #[test]
+    fn approx_eq_rel() {
+        let a: f32 = 1.0e9;
+        let b: f32 = 1.0001e9;
+
+        assert!(a.approx_eq_rel(&b, 1e-3));
+        assert!(!a.approx_eq_rel(&b, 1e-6));
+    }
+}
@@ -9,4 +9,32 @@ impl ApproxEq for f64 {
 
         true
     }
+
+    fn max_abs(&self) -> f64 {
+        self.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_eq_rel() {
+        let a = 1.0e9;
+        let b = 1.0e9 + 1.0;
+
+        assert!(a.approx_eq_rel(&b, 1e-6));
+        assert!(!a.approx_eq_default(&b));
+    }
+
+    #[test]
+    fn approx_eq_rel_small_values() {
+        let a = 3.0e-12;
+        let b = 3.1e-12;
+
+        assert!(a.approx_eq_default(&b));
+        assert!(!a.approx_eq_rel(&b, 1e-6));
+        assert!(a.approx_eq_rel(&b, 0.05));
+    }
 }
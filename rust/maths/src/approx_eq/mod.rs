@@ -1,4 +1,7 @@
+mod f32;
 mod f64;
+mod slice;
+mod tuple;
 
 pub trait ApproxEq {
     fn approx_eq(&self, other: &Self, eps: f64) -> bool;
@@ -6,4 +9,36 @@ pub trait ApproxEq {
     fn approx_eq_default(&self, other: &Self) -> bool {
         self.approx_eq(other, 1e-6)
     }
+
+    /// Magnitude that `approx_eq_rel` scales by: the largest absolute component. The default of
+    /// 1 makes `rel_eps` an absolute tolerance for types that don't override it.
+    fn max_abs(&self) -> f64 {
+        1.0
+    }
+
+    /// Compares with `rel_eps` scaled by the larger operand's `max_abs`, so a compound type uses
+    /// one overall scale and near-zero components compare equal to zero.
+    fn approx_eq_rel(&self, other: &Self, rel_eps: f64) -> bool {
+        let scale = self.max_abs().max(other.max_abs());
+        self.approx_eq(other, rel_eps * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Celsius(f64);
+
+    impl ApproxEq for Celsius {
+        fn approx_eq(&self, other: &Celsius, eps: f64) -> bool {
+            self.0.approx_eq(&other.0, eps)
+        }
+    }
+
+    #[test]
+    fn approx_eq_rel_defaults_to_absolute() {
+        assert!(Celsius(1000.0).approx_eq_rel(&Celsius(1000.5), 1.0));
+        assert!(!Celsius(1000.0).approx_eq_rel(&Celsius(1000.5), 1e-3));
+    }
 }
@@ -0,0 +1,67 @@
+use super::ApproxEq;
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn approx_eq(&self, other: &[T], eps: f64) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.approx_eq(b, eps))
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.iter().fold(0.0, |m, v| m.max(v.max_abs()))
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Vec<T>, eps: f64) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.as_slice().max_abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices_within_tolerance() {
+        let a = [1.0, 2.5, -3.0];
+        let b = [1.0000001, 2.4999999, -3.0];
+
+        assert!(a[..].approx_eq_default(&b[..]));
+        assert!(!a[..].approx_eq(&b[..], 1e-9));
+    }
+
+    #[test]
+    fn vecs_within_tolerance() {
+        let a = vec![0.1 + 0.2, 4.0];
+        let b = vec![0.3, 4.0];
+
+        assert!(a.approx_eq_default(&b));
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let a = vec![1.0, 2.0];
+        let b = vec![1.0, 2.0, 3.0];
+
+        assert!(!a.approx_eq_default(&b));
+        assert!(!b[..].approx_eq_default(&a[..]));
+    }
+
+    #[test]
+    fn relative_tolerance_per_element() {
+        let a = vec![1.0e9, 2.0e-9];
+        let b = vec![1.0e9 + 1.0, 2.000001e-9];
+
+        assert!(a.approx_eq_rel(&b, 1e-5));
+        assert!(!a.approx_eq(&b, 1e-5));
+    }
+}
@@ -0,0 +1,47 @@
+use super::ApproxEq;
+
+impl ApproxEq for (f64, f64) {
+    fn approx_eq(&self, other: &(f64, f64), eps: f64) -> bool {
+        self.0.approx_eq(&other.0, eps) && self.1.approx_eq(&other.1, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.0.abs().max(self.1.abs())
+    }
+}
+
+impl ApproxEq for (f64, f64, f64) {
+    fn approx_eq(&self, other: &(f64, f64, f64), eps: f64) -> bool {
+        self.0.approx_eq(&other.0, eps)
+            && self.1.approx_eq(&other.1, eps)
+            && self.2.approx_eq(&other.2, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.0.abs().max(self.1.abs()).max(self.2.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        assert!((1.0, 0.1 + 0.2).approx_eq_default(&(1.0, 0.3)));
+        assert!(!(1.0, 2.0).approx_eq_default(&(1.0, 2.1)));
+    }
+
+    #[test]
+    fn triples() {
+        assert!((1.0, -2.0, 0.1 + 0.2).approx_eq_default(&(1.0, -2.0, 0.3)));
+        assert!(!(1.0, -2.0, 3.0).approx_eq_default(&(1.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn relative_to_largest_component() {
+        assert!((1.0e9, 2.0e-9).approx_eq_rel(&(1.0e9 + 1.0, 0.0), 1e-5));
+        assert!((1.0, 0.0, -1.0).approx_eq_rel(&(1.0, 1e-17, -1.0), 1e-12));
+        assert!(!(1.0e9, 0.0).approx_eq_rel(&(1.0e9, 1e5), 1e-5));
+    }
+}
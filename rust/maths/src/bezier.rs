@@ -0,0 +1,47 @@
+use crate::vector2::Vector2;
+
+/// Evaluated by De Casteljau's algorithm; `t` outside `[0, 1]` extrapolates the curve.
+pub fn quadratic(p0: &Vector2, p1: &Vector2, p2: &Vector2, t: f64) -> Vector2 {
+    let a = p0.lerp(p1, t);
+    let b = p1.lerp(p2, t);
+
+    a.lerp(&b, t)
+}
+
+/// Evaluated by De Casteljau's algorithm; `t` outside `[0, 1]` extrapolates the curve.
+pub fn cubic(p0: &Vector2, p1: &Vector2, p2: &Vector2, p3: &Vector2, t: f64) -> Vector2 {
+    let a = p0.lerp(p1, t);
+    let b = p1.lerp(p2, t);
+    let c = p2.lerp(p3, t);
+
+    quadratic(&a, &b, &c, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn quadratic_curve() {
+        let p0 = Vector2::new(0, 0);
+        let p1 = Vector2::new(2, 4);
+        let p2 = Vector2::new(4, 0);
+
+        assert!(quadratic(&p0, &p1, &p2, 0.0).approx_eq_default(&p0));
+        assert!(quadratic(&p0, &p1, &p2, 1.0).approx_eq_default(&p2));
+        assert!(quadratic(&p0, &p1, &p2, 0.5).approx_eq_default(&Vector2::new(2, 2)));
+    }
+
+    #[test]
+    fn cubic_curve() {
+        let p0 = Vector2::new(0, 0);
+        let p1 = Vector2::new(0, 4);
+        let p2 = Vector2::new(4, 4);
+        let p3 = Vector2::new(4, 0);
+
+        assert!(cubic(&p0, &p1, &p2, &p3, 0.0).approx_eq_default(&p0));
+        assert!(cubic(&p0, &p1, &p2, &p3, 1.0).approx_eq_default(&p3));
+        assert!(cubic(&p0, &p1, &p2, &p3, 0.5).approx_eq_default(&Vector2::new(2, 3)));
+    }
+}
@@ -0,0 +1,12 @@
+use super::Complex;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Complex {
+    fn approx_eq(&self, other: &Complex, eps: f64) -> bool {
+        self.re.approx_eq(&other.re, eps) && self.im.approx_eq(&other.im, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.re.abs().max(self.im.abs())
+    }
+}
@@ -0,0 +1,136 @@
+use super::Complex;
+
+impl Complex {
+    pub fn new<R: Into<f64>, I: Into<f64>>(re: R, im: I) -> Complex {
+        Complex {
+            re: re.into(),
+            im: im.into(),
+        }
+    }
+
+    pub fn from_polar(r: f64, theta: f64) -> Complex {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    pub fn re(&self) -> f64 {
+        self.re
+    }
+
+    pub fn im(&self) -> f64 {
+        self.im
+    }
+
+    pub fn add(&self, other: &Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+
+    pub fn subtract(&self, other: &Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+
+    pub fn multiply(&self, other: &Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    pub fn divide(&self, other: &Complex) -> Complex {
+        let denominator = other.re * other.re + other.im * other.im;
+
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / denominator,
+            (self.im * other.re - self.re * other.im) / denominator,
+        )
+    }
+
+    pub fn conjugate(&self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn argument(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use std::f64::consts::PI;
+
+    struct ComplexResultCase {
+        a: Complex,
+        b: Complex,
+        expected: Complex,
+    }
+
+    #[test]
+    fn addition() {
+        let result = Complex::new(1, 2).add(&Complex::new(-3, 0.5));
+
+        assert!(result.approx_eq_default(&Complex::new(-2, 2.5)));
+    }
+
+    #[test]
+    fn multiplication() {
+        let cases: Vec<ComplexResultCase> = vec![
+            ComplexResultCase {
+                a: Complex::new(0, 1),
+                b: Complex::new(0, 1),
+                expected: Complex::new(-1, 0),
+            },
+            ComplexResultCase {
+                a: Complex::new(1, 2),
+                b: Complex::new(3, -1),
+                expected: Complex::new(5, 5),
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.multiply(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn subtraction_and_division() {
+        let a = Complex::new(5, 5);
+        let b = Complex::new(3, -1);
+
+        assert!(a.subtract(&b).approx_eq_default(&Complex::new(2, 6)));
+        assert!(a.divide(&b).approx_eq_default(&Complex::new(1, 2)));
+    }
+
+    #[test]
+    fn conjugate_and_magnitude() {
+        let z = Complex::new(3, 4);
+
+        assert!(z.conjugate().approx_eq_default(&Complex::new(3, -4)));
+        assert!(z.magnitude().approx_eq_default(&5.0));
+        assert!(
+            z.multiply(&z.conjugate())
+                .approx_eq_default(&Complex::new(25, 0))
+        );
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        let z = Complex::from_polar(2.0, PI / 3.0);
+
+        assert!(z.magnitude().approx_eq_default(&2.0));
+        assert!(z.argument().approx_eq_default(&(PI / 3.0)));
+        assert!(Complex::from_polar(z.magnitude(), z.argument()).approx_eq_default(&z));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Complex::new(1, 2)), "1 + 2i");
+        assert_eq!(format!("{}", Complex::new(1.5, -2)), "1.5 - 2i");
+    }
+}
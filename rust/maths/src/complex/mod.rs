@@ -0,0 +1,9 @@
+mod approx_eq;
+mod core;
+mod display;
+
+#[derive(PartialEq, Debug)]
+pub struct Complex {
+    re: f64,
+    im: f64,
+}
@@ -0,0 +1,12 @@
+use super::Dual;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Dual {
+    fn approx_eq(&self, other: &Dual, eps: f64) -> bool {
+        self.value.approx_eq(&other.value, eps) && self.deriv.approx_eq(&other.deriv, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.value.abs().max(self.deriv.abs())
+    }
+}
@@ -0,0 +1,105 @@
+use super::Dual;
+
+impl Dual {
+    pub fn new(value: f64, deriv: f64) -> Dual {
+        Dual { value, deriv }
+    }
+
+    pub fn constant(value: f64) -> Dual {
+        Dual::new(value, 0.0)
+    }
+
+    /// The input being differentiated with respect to, so its derivative is 1.
+    pub fn variable(value: f64) -> Dual {
+        Dual::new(value, 1.0)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn deriv(&self) -> f64 {
+        self.deriv
+    }
+
+    pub fn add(&self, other: &Dual) -> Dual {
+        Dual::new(self.value + other.value, self.deriv + other.deriv)
+    }
+
+    pub fn subtract(&self, other: &Dual) -> Dual {
+        Dual::new(self.value - other.value, self.deriv - other.deriv)
+    }
+
+    pub fn multiply(&self, other: &Dual) -> Dual {
+        Dual::new(
+            self.value * other.value,
+            self.deriv * other.value + self.value * other.deriv,
+        )
+    }
+
+    pub fn sin(&self) -> Dual {
+        Dual::new(self.value.sin(), self.deriv * self.value.cos())
+    }
+
+    pub fn cos(&self) -> Dual {
+        Dual::new(self.value.cos(), -self.deriv * self.value.sin())
+    }
+
+    pub fn powf(&self, exponent: f64) -> Dual {
+        Dual::new(
+            self.value.powf(exponent),
+            self.deriv * exponent * self.value.powf(exponent - 1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn square_derivative() {
+        let x = Dual::variable(3.0);
+
+        assert!(x.multiply(&x).approx_eq_default(&Dual::new(9.0, 6.0)));
+        assert!(x.powf(2.0).approx_eq_default(&Dual::new(9.0, 6.0)));
+    }
+
+    #[test]
+    fn sum_and_constants() {
+        // f(x) = x^3 - 2x + 5, f'(x) = 3x^2 - 2
+        let x = Dual::variable(2.0);
+        let result = x
+            .powf(3.0)
+            .subtract(&Dual::constant(2.0).multiply(&x))
+            .add(&Dual::constant(5.0));
+
+        assert!(result.approx_eq_default(&Dual::new(9.0, 10.0)));
+    }
+
+    #[test]
+    fn trigonometry() {
+        let x = Dual::variable(PI / 3.0);
+
+        assert!(
+            x.sin()
+                .approx_eq_default(&Dual::new(3f64.sqrt() / 2.0, 0.5))
+        );
+        assert!(
+            x.cos()
+                .approx_eq_default(&Dual::new(0.5, -(3f64.sqrt()) / 2.0))
+        );
+    }
+
+    #[test]
+    fn chain_rule() {
+        // f(x) = sin(x^2), f'(x) = 2x cos(x^2)
+        let x = Dual::variable(1.5);
+        let result = x.multiply(&x).sin();
+
+        assert!(result.value().approx_eq_default(&2.25f64.sin()));
+        assert!(result.deriv().approx_eq_default(&(3.0 * 2.25f64.cos())));
+    }
+}
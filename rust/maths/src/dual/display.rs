@@ -0,0 +1,8 @@
+use super::Dual;
+use std::fmt;
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.deriv)
+    }
+}
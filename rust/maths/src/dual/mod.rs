@@ -0,0 +1,10 @@
+mod approx_eq;
+mod core;
+mod display;
+
+/// A value paired with its derivative, for forward-mode automatic differentiation.
+#[derive(PartialEq, Debug)]
+pub struct Dual {
+    value: f64,
+    deriv: f64,
+}
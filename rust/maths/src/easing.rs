@@ -0,0 +1,90 @@
+pub fn ease_in_quad(t: f64) -> f64 {
+    t * t
+}
+
+pub fn ease_out_quad(t: f64) -> f64 {
+    t * (2.0 - t)
+}
+
+pub fn ease_in_out_quad(t: f64) -> f64 {
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - 2.0 * (1.0 - t) * (1.0 - t)
+    }
+}
+
+pub fn ease_in_cubic(t: f64) -> f64 {
+    t * t * t
+}
+
+pub fn ease_out_cubic(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+pub fn ease_in_out_cubic(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - 4.0 * (1.0 - t).powi(3)
+    }
+}
+
+fn normalize_edges(edge0: f64, edge1: f64, x: f64) -> f64 {
+    ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0)
+}
+
+/// Clamps `x` to the edges, so the result is always within `[0, 1]`.
+pub fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = normalize_edges(edge0, edge1, x);
+
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Like `smoothstep`, but with zero first and second derivatives at the edges.
+pub fn smootherstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = normalize_edges(edge0, edge1, x);
+
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn endpoints() {
+        let easings: Vec<fn(f64) -> f64> = vec![
+            ease_in_quad,
+            ease_out_quad,
+            ease_in_out_quad,
+            ease_in_cubic,
+            ease_out_cubic,
+            ease_in_out_cubic,
+            |x| smoothstep(0.0, 1.0, x),
+            |x| smootherstep(0.0, 1.0, x),
+        ];
+
+        for easing in easings {
+            assert!(easing(0.0).approx_eq_default(&0.0));
+            assert!(easing(1.0).approx_eq_default(&1.0));
+        }
+    }
+
+    #[test]
+    fn midpoints() {
+        assert!(ease_in_quad(0.5).approx_eq_default(&0.25));
+        assert!(ease_out_quad(0.5).approx_eq_default(&0.75));
+        assert!(ease_in_out_cubic(0.5).approx_eq_default(&0.5));
+        assert!(smoothstep(2.0, 4.0, 3.0).approx_eq_default(&0.5));
+        assert!(smootherstep(2.0, 4.0, 3.0).approx_eq_default(&0.5));
+    }
+
+    #[test]
+    fn smoothstep_clamps_outside_edges() {
+        assert!(smoothstep(2.0, 4.0, 1.0).approx_eq_default(&0.0));
+        assert!(smoothstep(2.0, 4.0, 5.0).approx_eq_default(&1.0));
+        assert!(smootherstep(2.0, 4.0, -10.0).approx_eq_default(&0.0));
+    }
+}
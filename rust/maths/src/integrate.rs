@@ -0,0 +1,74 @@
+use crate::vector3::Vector3;
+
+/// Position Verlet: `x' = 2x - x_prev + a dt²`.
+pub fn verlet(
+    position: &Vector3,
+    prev_position: &Vector3,
+    acceleration: &Vector3,
+    dt: f64,
+) -> Vector3 {
+    position
+        .multiply(2.0)
+        .subtract(prev_position)
+        .add(&acceleration.multiply(dt * dt))
+}
+
+/// Semi-implicit Euler: velocity is updated first and the new velocity moves the position.
+pub fn euler_step(
+    position: &Vector3,
+    velocity: &Vector3,
+    acceleration: &Vector3,
+    dt: f64,
+) -> (Vector3, Vector3) {
+    let velocity = velocity.add(&acceleration.multiply(dt));
+    let position = position.add(&velocity.multiply(dt));
+
+    (position, velocity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn verlet_constant_velocity() {
+        let mut prev = Vector3::new(0, 0, 0);
+        let mut position = Vector3::new(1, -0.5, 2);
+
+        for step in 2..6 {
+            let next = verlet(&position, &prev, &Vector3::zero(), 0.1);
+            prev = position;
+            position = next;
+
+            let expected = Vector3::new(step, -0.5 * step as f64, 2 * step);
+            assert!(position.approx_eq_default(&expected));
+        }
+    }
+
+    #[test]
+    fn verlet_constant_acceleration() {
+        let gravity = Vector3::new(0, -10, 0);
+        let result = verlet(
+            &Vector3::new(0, 5, 0),
+            &Vector3::new(0, 5, 0),
+            &gravity,
+            0.1,
+        );
+
+        assert!(result.approx_eq_default(&Vector3::new(0, 4.9, 0)));
+    }
+
+    #[test]
+    fn euler_step_constant_acceleration() {
+        let (position, velocity) = euler_step(
+            &Vector3::new(0, 10, 0),
+            &Vector3::new(2, 0, 0),
+            &Vector3::new(0, -10, 0),
+            0.5,
+        );
+
+        assert!(velocity.approx_eq_default(&Vector3::new(2, -5, 0)));
+        assert!(position.approx_eq_default(&Vector3::new(1, 7.5, 0)));
+    }
+}
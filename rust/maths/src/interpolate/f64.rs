@@ -0,0 +1,26 @@
+use super::Interpolate;
+
+impl Interpolate for f64 {
+    fn lerp(&self, other: &f64, t: f64) -> f64 {
+        self + (other - self) * t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn lerp() {
+        assert!(2.0.lerp(&6.0, 0.25).approx_eq_default(&3.0));
+        assert!(2.0.lerp(&6.0, 1.5).approx_eq_default(&8.0));
+    }
+
+    #[test]
+    fn smoothstep_interp() {
+        assert!(2.0.smoothstep_interp(&6.0, 0.5).approx_eq_default(&4.0));
+        assert!(2.0.smoothstep_interp(&6.0, 0.25).approx_eq_default(&2.625));
+        assert!(2.0.smoothstep_interp(&6.0, 2.0).approx_eq_default(&6.0));
+    }
+}
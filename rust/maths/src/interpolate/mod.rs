@@ -0,0 +1,13 @@
+mod f64;
+
+use crate::easing::smoothstep;
+
+pub trait Interpolate: Sized {
+    /// `t` is not clamped, so values outside `[0, 1]` extrapolate.
+    fn lerp(&self, other: &Self, t: f64) -> Self;
+
+    /// Eases in and out of the endpoints; `t` is clamped to `[0, 1]`.
+    fn smoothstep_interp(&self, other: &Self, t: f64) -> Self {
+        self.lerp(other, smoothstep(0.0, 1.0, t))
+    }
+}
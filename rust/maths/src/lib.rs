@@ -1,4 +1,26 @@
+pub mod aabb;
 pub mod approx_eq;
+pub mod bezier;
+pub mod complex;
+pub mod dual;
+pub mod easing;
+pub mod integrate;
+pub mod interpolate;
 pub mod matrix;
+pub mod pathfinding;
+pub mod plane;
+pub mod polygon;
+pub mod polynomial;
+pub mod quaternion;
+#[cfg(feature = "random")]
+pub mod random;
+pub mod ray;
+pub mod segment;
+pub mod spatial_grid;
+pub mod sphere;
+pub mod spline;
+pub mod transform;
+pub mod triangle;
 pub mod vector2;
 pub mod vector3;
+pub mod vector_n;
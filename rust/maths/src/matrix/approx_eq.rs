@@ -15,4 +15,79 @@ impl ApproxEq for Matrix {
             .zip(other.data.iter())
             .all(|(a, b)| a.approx_eq(b, eps))
     }
+
+    fn max_abs(&self) -> f64 {
+        self.data.max_abs()
+    }
+}
+
+impl Matrix {
+    /// Inherent shorthand for `ApproxEq::approx_eq`, so callers need not import the trait.
+    pub fn approx_equals(&self, other: &Matrix, eps: f64) -> bool {
+        self.approx_eq(other, eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn approx_equals() {
+        let a = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 2;
+            3, 4
+        );
+        let close = matrix!(
+            rows: 2,
+            cols: 2,
+            1.0005, 2;
+            3, 3.9995
+        );
+
+        assert!(a.approx_equals(&close, 1e-3));
+        assert!(!a.approx_equals(&close, 1e-4));
+    }
+
+    #[test]
+    fn approx_equals_shape_mismatch() {
+        let a = Matrix::zeros(2, 3);
+        let b = Matrix::zeros(3, 2);
+
+        assert!(!a.approx_equals(&b, 1.0));
+    }
+
+    #[test]
+    fn approx_eq_rel_uses_overall_scale() {
+        let a = matrix!(
+            rows: 2,
+            cols: 2,
+            0, -1;
+            1, 0
+        );
+        let rounded = matrix!(
+            rows: 2,
+            cols: 2,
+            1e-17, -1;
+            1, -6.1e-17
+        );
+        let large = matrix!(
+            rows: 1,
+            cols: 2,
+            1e9, 0;
+        );
+        let large_close = matrix!(
+            rows: 1,
+            cols: 2,
+            1e9 + 1.0, 0.5;
+        );
+
+        assert!(a.approx_eq_rel(&rounded, 1e-12));
+        assert!(!a.approx_eq_rel(&matrix![0, -1; 1, 1e-3], 1e-6));
+        assert!(large.approx_eq_rel(&large_close, 1e-8));
+        assert!(!large.approx_eq_rel(&large_close, 1e-10));
+    }
 }
@@ -44,6 +44,33 @@ impl Matrix {
 
         true
     }
+
+    /// True for a square matrix within `eps` of the identity, entry by entry.
+    pub fn is_identity(&self, eps: f64) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        self.data.iter().enumerate().all(|(index, value)| {
+            let expected = if index / self.columns == index % self.columns {
+                1.0
+            } else {
+                0.0
+            };
+            value.approx_eq(&expected, eps)
+        })
+    }
+
+    /// True when `AᵀA` is within `eps` of the identity; non-square matrices are never orthogonal.
+    pub fn is_orthogonal(&self, eps: f64) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        self.transpose()
+            .multiply(self)
+            .is_ok_and(|product| product.approx_eq(&Matrix::identity(self.rows), eps))
+    }
 }
 
 #[cfg(test)]
@@ -242,4 +269,49 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn is_orthogonal() {
+        let angle: f64 = 0.3;
+        let rotation = matrix!(
+            rows: 2,
+            cols: 2,
+            angle.cos(), -angle.sin();
+            angle.sin(), angle.cos()
+        );
+        let scaling = matrix!(
+            rows: 2,
+            cols: 2,
+            2, 0;
+            0, 2
+        );
+
+        assert!(rotation.is_orthogonal(1e-9));
+        assert!(Matrix::identity(3).is_orthogonal(1e-9));
+        assert!(!scaling.is_orthogonal(1e-9));
+        assert!(!Matrix::zeros(2, 3).is_orthogonal(1e-9));
+    }
+
+    #[test]
+    fn is_identity() {
+        let perturbed = matrix!(
+            rows: 3,
+            cols: 3,
+            1.0000001, 0, -0.0000001;
+            0, 0.9999999, 0;
+            0.0000001, 0, 1
+        );
+        let non_identity = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 0.5;
+            0, 1
+        );
+
+        assert!(Matrix::identity(4).is_identity(1e-9));
+        assert!(perturbed.is_identity(1e-6));
+        assert!(!perturbed.is_identity(1e-9));
+        assert!(!non_identity.is_identity(1e-6));
+        assert!(!Matrix::zeros(2, 3).is_identity(1e-6));
+    }
 }
@@ -0,0 +1,390 @@
+use super::{Matrix, MatrixError};
+use crate::quaternion::Quaternion;
+use crate::vector3::Vector3;
+
+const GIMBAL_LOCK_EPSILON: f64 = 1e-9;
+const AFFINE_EPSILON: f64 = 1e-9;
+const SCALE_EPSILON: f64 = 1e-12;
+
+impl Matrix {
+    /// Extracts the rotation of a 3x3 rotation matrix using the trace-based method.
+    pub fn to_quaternion(&self) -> Result<Quaternion, MatrixError> {
+        if self.rows != 3 || self.columns != 3 {
+            return Err(MatrixError::IncompatibleDimensions);
+        }
+
+        let m = |row: usize, column: usize| self.data[self.get_index_ok(row, column)];
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::new(
+                0.25 * s,
+                (m(2, 1) - m(1, 2)) / s,
+                (m(0, 2) - m(2, 0)) / s,
+                (m(1, 0) - m(0, 1)) / s,
+            )
+        } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+            let s = (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * 2.0;
+            Quaternion::new(
+                (m(2, 1) - m(1, 2)) / s,
+                0.25 * s,
+                (m(0, 1) + m(1, 0)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+            )
+        } else if m(1, 1) > m(2, 2) {
+            let s = (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * 2.0;
+            Quaternion::new(
+                (m(0, 2) - m(2, 0)) / s,
+                (m(0, 1) + m(1, 0)) / s,
+                0.25 * s,
+                (m(1, 2) + m(2, 1)) / s,
+            )
+        } else {
+            let s = (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * 2.0;
+            Quaternion::new(
+                (m(1, 0) - m(0, 1)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+                (m(1, 2) + m(2, 1)) / s,
+                0.25 * s,
+            )
+        };
+
+        Ok(q.normalize())
+    }
+
+    /// Splits an affine 4x4 transform into translation, rotation and scale. A reflection is
+    /// reported as a negative x scale; a bottom row other than `[0, 0, 0, 1]` gives
+    /// `NotAffine`, and an axis negligible next to the largest one gives `Singular`.
+    pub fn decompose_trs(&self) -> Result<(Vector3, Quaternion, Vector3), MatrixError> {
+        if self.rows != 4 || self.columns != 4 {
+            return Err(MatrixError::IncompatibleDimensions);
+        }
+
+        let m = |row: usize, column: usize| self.data[self.get_index_ok(row, column)];
+        let affine = (0..4).all(|column| {
+            let expected = if column == 3 { 1.0 } else { 0.0 };
+            (m(3, column) - expected).abs() <= AFFINE_EPSILON
+        });
+        if !affine {
+            return Err(MatrixError::NotAffine);
+        }
+
+        let translation = Vector3::new(m(0, 3), m(1, 3), m(2, 3));
+
+        let mut basis = Matrix::from_fn(3, 3, m);
+        let mut scale = [0.0; 3];
+        for (column, factor) in scale.iter_mut().enumerate() {
+            *factor = (0..3).map(|row| m(row, column).powi(2)).sum::<f64>().sqrt();
+        }
+        let largest = scale.iter().fold(0.0_f64, |acc, factor| acc.max(*factor));
+        if scale
+            .iter()
+            .any(|factor| *factor <= largest * SCALE_EPSILON)
+        {
+            return Err(MatrixError::Singular);
+        }
+        if basis.determinant()? < 0.0 {
+            scale[0] = -scale[0];
+        }
+        for (index, value) in basis.data.iter_mut().enumerate() {
+            *value /= scale[index % 3];
+        }
+
+        Ok((translation, basis.to_quaternion()?, Vector3::from(scale)))
+    }
+
+    /// Rotation applied as roll about x, then pitch about y, then yaw about z, so
+    /// `R = Rz(yaw) * Ry(pitch) * Rx(roll)`.
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Matrix {
+        let (sr, cr) = roll.sin_cos();
+        let (sp, cp) = pitch.sin_cos();
+        let (sy, cy) = yaw.sin_cos();
+
+        Matrix {
+            rows: 3,
+            columns: 3,
+            data: vec![
+                cy * cp,
+                cy * sp * sr - sy * cr,
+                cy * sp * cr + sy * sr,
+                sy * cp,
+                sy * sp * sr + cy * cr,
+                sy * sp * cr - cy * sr,
+                -sp,
+                cp * sr,
+                cp * cr,
+            ],
+        }
+    }
+
+    /// Returns `(roll, pitch, yaw)` in the order used by `from_euler`, assuming `self` is a 3x3
+    /// rotation matrix. At gimbal lock (pitch of ±90°) roll is reported as zero and the
+    /// shared rotation is folded into yaw.
+    pub fn to_euler(&self) -> Result<(f64, f64, f64), MatrixError> {
+        if self.rows != 3 || self.columns != 3 {
+            return Err(MatrixError::IncompatibleDimensions);
+        }
+
+        let m = |row: usize, column: usize| self.data[self.get_index_ok(row, column)];
+        let pitch = (-m(2, 0)).clamp(-1.0, 1.0).asin();
+
+        if m(2, 0).abs() > 1.0 - GIMBAL_LOCK_EPSILON {
+            return Ok((0.0, pitch, (-m(0, 1)).atan2(m(1, 1))));
+        }
+
+        Ok((m(2, 1).atan2(m(2, 2)), pitch, m(1, 0).atan2(m(0, 0))))
+    }
+
+    /// Ragged rows are rejected with `IncorrectDataSize`.
+    pub fn from_rows(rows: &[Vec<f64>]) -> Result<Matrix, MatrixError> {
+        let columns = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != columns) {
+            return Err(MatrixError::IncorrectDataSize);
+        }
+
+        Matrix::new(rows.len(), columns, rows.concat())
+    }
+
+    pub fn from_vector3_rows(rows: &[Vector3]) -> Matrix {
+        Matrix {
+            rows: rows.len(),
+            columns: 3,
+            data: rows.iter().flat_map(|v| v.to_array()).collect(),
+        }
+    }
+
+    /// Accepts either a 3x1 column or a 1x3 row.
+    pub fn to_vector3(&self) -> Result<Vector3, MatrixError> {
+        match (self.rows, self.columns) {
+            (3, 1) | (1, 3) => Ok(Vector3::new(self.data[0], self.data[1], self.data[2])),
+            _ => Err(MatrixError::IncompatibleDimensions),
+        }
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<f64>> {
+        // `chunks` rejects a size of zero, and an Rx0 matrix still has R (empty) rows
+        if self.columns == 0 {
+            return vec![Vec::new(); self.rows];
+        }
+
+        self.data
+            .chunks(self.columns)
+            .map(|row| row.to_vec())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::transform::Transform;
+    use crate::vector3::Vector3;
+    use std::f64::consts::PI;
+
+    fn same_rotation(a: &Quaternion, b: &Quaternion) -> bool {
+        a.dot_product(b).abs().approx_eq_default(&1.0)
+    }
+
+    #[test]
+    fn quaternion_round_trip() {
+        let cases: Vec<(Vector3, f64)> = vec![
+            (Vector3::new(0, 0, 1), 0.0),
+            (Vector3::new(0, 0, 1), PI / 2.0),
+            (Vector3::new(1, 0, 0), PI),
+            (Vector3::new(0, 1, 0), 3.0),
+            (Vector3::new(1, 2, 3), 1.1),
+            (Vector3::new(-2, 0.5, 1), -2.4),
+        ];
+
+        for (axis, radians) in cases {
+            let q = Quaternion::from_axis_angle(&axis, radians);
+
+            let result = q.to_matrix().to_quaternion().expect("3x3 should convert");
+
+            assert!(same_rotation(&result, &q), "{} should match {}", result, q);
+        }
+    }
+
+    #[test]
+    fn to_quaternion_requires_3x3() {
+        let result = Matrix::identity(4).to_quaternion();
+
+        assert_eq!(result, Err(MatrixError::IncompatibleDimensions));
+    }
+
+    #[test]
+    fn to_rows() {
+        let m = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+
+        assert_eq!(m.to_rows(), vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    }
+
+    #[test]
+    fn rows_round_trip() {
+        let cases: Vec<Matrix> = vec![
+            Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap(),
+            Matrix::identity(4),
+            Matrix::zeros(3, 1),
+            Matrix::zeros(3, 0),
+        ];
+
+        for case in cases {
+            let result = Matrix::from_rows(&case.to_rows()).unwrap();
+
+            assert_eq!(result, case);
+        }
+    }
+
+    #[test]
+    fn from_ragged_rows() {
+        let rows = vec![vec![1.0, 2.0], vec![3.0]];
+
+        assert_eq!(
+            Matrix::from_rows(&rows),
+            Err(MatrixError::IncorrectDataSize)
+        );
+    }
+
+    #[test]
+    fn from_euler_single_axes() {
+        let yaw = Matrix::from_euler(0.0, 0.0, PI / 2.0);
+        let expected = Quaternion::from_axis_angle(&Vector3::unit_z(), PI / 2.0).to_matrix();
+
+        assert!(yaw.approx_eq_default(&expected));
+        assert!(Matrix::from_euler(0.0, 0.0, 0.0).approx_eq_default(&Matrix::identity(3)));
+    }
+
+    #[test]
+    fn euler_round_trip() {
+        let cases: Vec<(f64, f64, f64)> = vec![
+            (0.1, 0.2, 0.3),
+            (-1.2, 0.7, 2.5),
+            (3.0, -1.4, -0.4),
+            (0.0, 0.0, 0.0),
+        ];
+
+        for (roll, pitch, yaw) in cases {
+            let result = Matrix::from_euler(roll, pitch, yaw).to_euler().unwrap();
+
+            assert!(result.approx_eq_default(&(roll, pitch, yaw)));
+        }
+    }
+
+    #[test]
+    fn euler_gimbal_lock() {
+        let m = Matrix::from_euler(0.3, PI / 2.0, 0.5);
+
+        let (roll, pitch, yaw) = m.to_euler().unwrap();
+
+        assert_eq!(roll, 0.0);
+        assert!(pitch.approx_eq_default(&(PI / 2.0)));
+        assert!(Matrix::from_euler(roll, pitch, yaw).approx_eq_default(&m));
+    }
+
+    #[test]
+    fn to_euler_requires_3x3() {
+        assert_eq!(
+            Matrix::identity(4).to_euler(),
+            Err(MatrixError::IncompatibleDimensions)
+        );
+        assert_eq!(
+            Matrix::zeros(3, 2).to_euler(),
+            Err(MatrixError::IncompatibleDimensions)
+        );
+    }
+
+    #[test]
+    fn decompose_trs() {
+        let cases: Vec<Transform> = vec![
+            Transform::new(
+                Vector3::new(1, -2, 3),
+                Quaternion::from_axis_angle(&Vector3::new(1, 1, 0), 0.8),
+                Vector3::new(2, 0.5, 3),
+            ),
+            Transform::new(
+                Vector3::new(0, 4, 0),
+                Quaternion::from_axis_angle(&Vector3::unit_y(), -2.0),
+                Vector3::new(-2, 1, 1.5),
+            ),
+        ];
+
+        for case in cases {
+            let (translation, rotation, scale) = case.to_matrix().decompose_trs().unwrap();
+            let rebuilt = Transform::new(translation, rotation, scale);
+
+            assert!(rebuilt.translation().approx_eq_default(case.translation()));
+            assert!(rebuilt.scale().approx_eq_default(case.scale()));
+            assert!(
+                rebuilt
+                    .rotation()
+                    .to_matrix()
+                    .approx_eq_default(&case.rotation().to_matrix())
+            );
+        }
+    }
+
+    #[test]
+    fn decompose_trs_errors() {
+        let mut flat = Matrix::identity(4);
+        flat.set(1, 1, 0.0).unwrap();
+
+        assert_eq!(
+            Matrix::identity(3).decompose_trs(),
+            Err(MatrixError::IncompatibleDimensions)
+        );
+        assert_eq!(flat.decompose_trs(), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn decompose_trs_rejects_projective() {
+        let cases: Vec<(usize, f64)> = vec![(0, 0.5), (2, -1.0), (3, 2.0)];
+
+        for (column, value) in cases {
+            let mut projective = Matrix::identity(4);
+            projective.set(3, column, value).unwrap();
+            assert_eq!(projective.decompose_trs(), Err(MatrixError::NotAffine));
+        }
+    }
+
+    #[test]
+    fn decompose_trs_negligible_scale() {
+        let mut squashed = Matrix::identity(4);
+        squashed.set(2, 2, 1e-300).unwrap();
+        assert_eq!(squashed.decompose_trs(), Err(MatrixError::Singular));
+
+        let mut tiny = Matrix::identity(4);
+        for axis in 0..3 {
+            tiny.set(axis, axis, 1e-100).unwrap();
+        }
+        let (_, rotation, scale) = tiny.decompose_trs().unwrap();
+        assert!(rotation.approx_eq_default(&Quaternion::identity()));
+        assert!(scale.approx_eq_rel(&Vector3::new(1e-100, 1e-100, 1e-100), 1e-9));
+    }
+
+    #[test]
+    fn from_vector3_rows() {
+        let m = Matrix::from_vector3_rows(&[Vector3::new(1, 2, 3), Vector3::new(-4, 5.5, 6)]);
+
+        assert_eq!(m.rows, 2);
+        assert_eq!(m.columns, 3);
+        assert_eq!(m.get(0, 2).unwrap(), 3.0);
+        assert_eq!(m.get(1, 0).unwrap(), -4.0);
+        assert_eq!(m.get(1, 1).unwrap(), 5.5);
+    }
+
+    #[test]
+    fn to_vector3() {
+        let expected = Vector3::new(1, -2, 3.5);
+        let column = Matrix::new(3, 1, vec![1.0, -2.0, 3.5]).unwrap();
+        let row = Matrix::new(1, 3, vec![1.0, -2.0, 3.5]).unwrap();
+
+        assert_eq!(column.to_vector3(), Ok(expected));
+        assert_eq!(row.to_vector3(), Ok(Vector3::new(1, -2, 3.5)));
+        assert_eq!(
+            Matrix::identity(2).to_vector3(),
+            Err(MatrixError::IncompatibleDimensions)
+        );
+    }
+}
@@ -42,6 +42,75 @@ impl Matrix {
         };
     }
 
+    /// Turns a square matrix into the identity in place; non-square matrices give
+    /// `SquareMatrixRequired` and are left untouched.
+    pub fn reset_identity(&mut self) -> Result<(), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+
+        self.reset_zeros();
+        for i in 0..self.rows {
+            let index = self.get_index_ok(i, i);
+            self.data[index] = 1.0;
+        }
+
+        Ok(())
+    }
+
+    /// Zeroes every entry, reusing the existing buffer rather than reallocating.
+    pub fn reset_zeros(&mut self) {
+        self.data.fill(0.0);
+    }
+
+    pub fn filled(rows: usize, columns: usize, value: f64) -> Self {
+        Matrix {
+            rows,
+            columns,
+            data: vec![value; rows * columns],
+        }
+    }
+
+    pub fn from_fn(rows: usize, columns: usize, f: impl Fn(usize, usize) -> f64) -> Self {
+        Matrix {
+            rows,
+            columns,
+            data: (0..rows * columns)
+                .map(|i| f(i / columns, i % columns))
+                .collect(),
+        }
+    }
+
+    pub fn from_diagonal(values: &[f64]) -> Self {
+        let mut m = Matrix::square_zeros(values.len());
+
+        for (i, value) in values.iter().enumerate() {
+            let index = m.get_index_ok(i, i);
+            m.data[index] = *value;
+        }
+
+        m
+    }
+
+    pub fn diagonal(&self) -> Result<Vec<f64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+
+        Ok((0..self.rows)
+            .map(|i| self.data[self.get_index_ok(i, i)])
+            .collect())
+    }
+
+    /// Reinterprets a clone of the row-major data with new dimensions.
+    pub fn reshape(&self, rows: usize, columns: usize) -> Result<Matrix, MatrixError> {
+        Matrix::new(rows, columns, self.data.clone())
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
     pub fn get(&self, row: usize, column: usize) -> Result<f64, MatrixError> {
         return match self.get_index(row, column) {
             Ok(v) => Ok(self.data[v]),
@@ -58,6 +127,18 @@ impl Matrix {
             Err(e) => Err(e),
         };
     }
+
+    /// Skips the row and column validation of `get`; the caller must ensure both are in range.
+    /// An out-of-range column silently reads a neighbouring row's cell.
+    pub fn get_unchecked(&self, row: usize, column: usize) -> f64 {
+        self.data[self.get_index_ok(row, column)]
+    }
+
+    /// Skips the row and column validation of `set`; the caller must ensure both are in range.
+    pub fn set_unchecked(&mut self, row: usize, column: usize, value: f64) {
+        let index = self.get_index_ok(row, column);
+        self.data[index] = value;
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +192,46 @@ mod tests {
         assert_eq!(at12_after, 4.78);
     }
 
+    #[test]
+    fn reset_in_place() {
+        let mut m = Matrix::from_fn(3, 3, |row, column| (row * 3 + column) as f64);
+
+        m.reset_identity().unwrap();
+        assert_eq!(m, Matrix::identity(3));
+
+        m.set(0, 2, 4.0).unwrap();
+        m.reset_zeros();
+        assert_eq!(m, Matrix::zeros(3, 3));
+    }
+
+    #[test]
+    fn reset_identity_requires_square() {
+        let mut m = Matrix::filled(2, 3, 1.0);
+
+        assert_eq!(m.reset_identity(), Err(MatrixError::SquareMatrixRequired));
+        assert_eq!(m, Matrix::filled(2, 3, 1.0));
+    }
+
+    #[test]
+    fn shape() {
+        assert_eq!(Matrix::zeros(2, 3).shape(), (2, 3));
+        assert_eq!(Matrix::identity(4).shape(), (4, 4));
+    }
+
+    #[test]
+    fn unchecked_access_matches_checked() {
+        let mut m = Matrix::from_fn(3, 4, |row, column| (row * 10 + column) as f64);
+
+        for row in 0..3 {
+            for column in 0..4 {
+                assert_eq!(m.get_unchecked(row, column), m.get(row, column).unwrap());
+            }
+        }
+
+        m.set_unchecked(2, 1, -7.5);
+        assert_eq!(m.get(2, 1).unwrap(), -7.5);
+    }
+
     #[test]
     fn get_set_out_of_bounds() {
         let mut m = Matrix::zeros(2, 2);
@@ -127,5 +248,52 @@ mod tests {
         assert!(m.is_err());
     }
 
+    #[test]
+    fn filled() {
+        let m = Matrix::filled(2, 3, 1.5);
+
+        assert_eq!(m.rows, 2);
+        assert_eq!(m.columns, 3);
+        assert!(m.data.iter().all(|v| *v == 1.5));
+    }
+
+    #[test]
+    fn from_fn_multiplication_table() {
+        let m = Matrix::from_fn(4, 5, |row, column| ((row + 1) * (column + 1)) as f64);
+
+        assert_eq!(m.get(0, 0).unwrap(), 1.0);
+        assert_eq!(m.get(2, 3).unwrap(), 12.0);
+        assert_eq!(m.get(3, 4).unwrap(), 20.0);
+    }
+
+    #[test]
+    fn from_diagonal() {
+        let m = Matrix::from_diagonal(&[2.0, 3.0]);
+
+        assert_eq!(m, Matrix::new(2, 2, vec![2.0, 0.0, 0.0, 3.0]).unwrap());
+        assert_eq!(m.diagonal(), Ok(vec![2.0, 3.0]));
+    }
+
+    #[test]
+    fn reshape() {
+        let m = Matrix::new(2, 6, (0..12).map(f64::from).collect()).unwrap();
+
+        let result = m.reshape(3, 4).expect("sizes match");
+
+        assert_eq!(result.rows, 3);
+        assert_eq!(result.columns, 4);
+        assert_eq!(result.data, m.data);
+        assert_eq!(result.get(1, 0).unwrap(), 4.0);
+        assert_eq!(m.reshape(5, 2), Err(MatrixError::IncorrectDataSize));
+    }
+
+    #[test]
+    fn diagonal_requires_square() {
+        assert_eq!(
+            Matrix::zeros(2, 3).diagonal(),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+
     use super::*;
 }
@@ -26,3 +26,51 @@ impl Display for Matrix {
         Ok(())
     }
 }
+
+/// Lays the entries out as a right-aligned grid under a `Matrix RxC` header.
+impl fmt::Debug for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix {}x{}", self.rows, self.columns)?;
+
+        let cells: Vec<String> = self.data.iter().map(|v| format!("{:?}", v)).collect();
+        let width = cells.iter().map(|c| c.len()).max().unwrap_or(0);
+        for row in cells.chunks(self.columns.max(1)).take(self.rows) {
+            writeln!(f)?;
+            write!(f, "[")?;
+            for (c, cell) in row.iter().enumerate() {
+                if c != 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{:>width$}", cell)?;
+            }
+            write!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn debug_grid() {
+        let m = matrix!(
+            rows: 2,
+            cols: 3,
+            1, -2.5, 3;
+            40, 5, 6
+        );
+
+        let result = format!("{:?}", m);
+
+        assert_eq!(result, "Matrix 2x3\n[ 1.0 -2.5  3.0]\n[40.0  5.0  6.0]");
+    }
+
+    #[test]
+    fn debug_empty() {
+        assert_eq!(format!("{:?}", Matrix::zeros(0, 3)), "Matrix 0x3");
+    }
+}
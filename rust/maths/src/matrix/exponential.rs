@@ -0,0 +1,116 @@
+use super::{Matrix, MatrixError};
+
+/// Degree of the diagonal Padé approximant. With the input scaled to an infinity norm of at
+/// most 0.5, degree 6 is accurate to around double precision; each extra squaring step
+/// doubles the rounding error, so large norms lose a few digits.
+const PADE_DEGREE: usize = 6;
+
+/// Enough squarings to bring any finite norm down to 0.5.
+const MAX_SQUARINGS: u32 = 1024;
+
+impl Matrix {
+    /// Matrix exponential by scaling and squaring with a Padé approximant. Infinite or NaN
+    /// entries, or a norm that overflows, give `NonFinite`.
+    pub fn exp(&self) -> Result<Matrix, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+
+        let norm = self.inf_norm();
+        // `inf_norm` folds with `f64::max`, which skips NaN, so check the entries as well
+        if !norm.is_finite() || self.data.iter().any(|value| !value.is_finite()) {
+            return Err(MatrixError::NonFinite);
+        }
+        let squarings = if norm > 0.5 {
+            ((norm / 0.5).log2().ceil() as u32).min(MAX_SQUARINGS)
+        } else {
+            0
+        };
+        let scale = 0.5_f64.powi(squarings as i32);
+        let scaled = Matrix::from_fn(self.rows, self.columns, |row, column| {
+            self.data[self.get_index_ok(row, column)] * scale
+        });
+
+        let mut numerator = Matrix::identity(self.rows);
+        let mut denominator = Matrix::identity(self.rows);
+        let mut power = Matrix::identity(self.rows);
+        let mut coefficient = 1.0;
+        for k in 1..=PADE_DEGREE {
+            coefficient *= (PADE_DEGREE - k + 1) as f64 / (k * (2 * PADE_DEGREE - k + 1)) as f64;
+            power = power.multiply(&scaled)?;
+
+            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+            for (index, value) in power.data.iter().enumerate() {
+                numerator.data[index] += coefficient * value;
+                denominator.data[index] += sign * coefficient * value;
+            }
+        }
+
+        let mut result = denominator.inverse()?.multiply(&numerator)?;
+        for _ in 0..squarings {
+            result = result.multiply(&result)?;
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::matrix;
+    use crate::quaternion::Quaternion;
+    use crate::vector3::Vector3;
+
+    #[test]
+    fn exp_of_zero_is_identity() {
+        let result = Matrix::zeros(3, 3).exp().unwrap();
+
+        assert!(result.approx_eq_default(&Matrix::identity(3)));
+    }
+
+    #[test]
+    fn exp_of_diagonal() {
+        let result = Matrix::from_diagonal(&[1.0, -2.0, 5.0]).exp().unwrap();
+        let expected = Matrix::from_diagonal(&[1f64.exp(), (-2f64).exp(), 5f64.exp()]);
+
+        assert!(result.approx_eq(&expected, 1e-9));
+    }
+
+    #[test]
+    fn exp_of_skew_symmetric_is_rotation() {
+        let angle: f64 = 2.5;
+        let skew = matrix!(
+            rows: 3,
+            cols: 3,
+            0, -angle, 0;
+            angle, 0, 0;
+            0, 0, 0
+        );
+        let expected = Quaternion::from_axis_angle(&Vector3::unit_z(), angle).to_matrix();
+
+        let result = skew.exp().unwrap();
+
+        assert!(result.approx_eq(&expected, 1e-9));
+        assert!(result.is_orthogonal(1e-9));
+    }
+
+    #[test]
+    fn exp_requires_square() {
+        assert_eq!(
+            Matrix::zeros(2, 3).exp(),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+
+    #[test]
+    fn exp_rejects_non_finite() {
+        let cases = [f64::INFINITY, f64::NEG_INFINITY, f64::NAN, f64::MAX];
+
+        for value in cases {
+            let m = Matrix::new(2, 2, vec![value, value, 0.0, 1.0]).unwrap();
+            assert_eq!(m.exp(), Err(MatrixError::NonFinite));
+        }
+    }
+}
@@ -1,6 +1,34 @@
 #[macro_export]
 
 macro_rules! matrix {
+    (
+        rows: $rows:expr,
+        cols: $cols:expr,
+        $(
+            $( $x:expr ),+ $(,)?
+        );+ $(;)?
+    ) => {{
+        $crate::try_matrix!(
+            rows: $rows,
+            cols: $cols,
+            $( $( $x ),+ );+
+        )
+        .unwrap()
+    }};
+    (
+        $(
+            $( $x:expr ),+ $(,)?
+        );+ $(;)?
+    ) => {{
+        $crate::try_matrix!(
+            $( $( $x ),+ );+
+        )
+        .expect("matrix! rows must all have the same number of columns")
+    }};
+}
+
+#[macro_export]
+macro_rules! try_matrix {
     (
         rows: $rows:expr,
         cols: $cols:expr,
@@ -16,6 +44,229 @@ macro_rules! matrix {
             )+
         ];
 
-        Matrix::new($rows, $cols, data).unwrap()
+        $crate::matrix::Matrix::new($rows, $cols, data)
+    }};
+    (
+        $(
+            $( $x:expr ),+ $(,)?
+        );+ $(;)?
+    ) => {{
+        let rows: Vec<Vec<f64>> = vec![
+            $(
+                vec![$( $x as f64, )+],
+            )+
+        ];
+        let cols = rows[0].len();
+
+        if rows.iter().any(|row| row.len() != cols) {
+            Err($crate::matrix::MatrixError::IncorrectDataSize)
+        } else {
+            $crate::matrix::Matrix::new(rows.len(), cols, rows.concat())
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! col_vector {
+    ( $( $x:expr ),+ $(,)? ) => {{
+        let data = vec![$( $x as f64, )+];
+
+        $crate::matrix::Matrix::new(data.len(), 1, data).unwrap()
+    }};
+}
+
+#[macro_export]
+macro_rules! row_vector {
+    ( $( $x:expr ),+ $(,)? ) => {{
+        let data = vec![$( $x as f64, )+];
+
+        $crate::matrix::Matrix::new(1, data.len(), data).unwrap()
     }};
 }
+
+#[macro_export]
+macro_rules! approx_matrix {
+    ( $a:expr, $b:expr, $eps:expr $(,)? ) => {{
+        let (a, b, eps): (&$crate::matrix::Matrix, &$crate::matrix::Matrix, f64) = (&$a, &$b, $eps);
+
+        assert!(
+            a.rows == b.rows && a.columns == b.columns,
+            "matrix shapes differ: {}x{} vs {}x{}",
+            a.rows,
+            a.columns,
+            b.rows,
+            b.columns
+        );
+        for row in 0..a.rows {
+            for col in 0..a.columns {
+                let x = a.get(row, col).unwrap();
+                let y = b.get(row, col).unwrap();
+                assert!(
+                    (x - y).abs() <= eps,
+                    "matrices differ at ({}, {}): {} vs {} (eps {})\n{}\n!=\n{}",
+                    row,
+                    col,
+                    x,
+                    y,
+                    eps,
+                    a,
+                    b
+                );
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{Matrix, MatrixError};
+
+    #[test]
+    fn infer_dimensions() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6
+        ];
+
+        assert_eq!(
+            m,
+            Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn infer_dimensions_single_row() {
+        let m = matrix![1.5, 2.5];
+
+        assert_eq!(m, Matrix::new(1, 2, vec![1.5, 2.5]).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "same number of columns")]
+    fn infer_dimensions_ragged_rows() {
+        matrix![
+            1, 2;
+            3
+        ];
+    }
+
+    #[test]
+    fn try_matrix() {
+        let m = try_matrix!(
+            rows: 2,
+            cols: 2,
+            1, 2;
+            3, 4
+        );
+
+        assert_eq!(m, Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+    }
+
+    #[test]
+    fn try_matrix_size_mismatch() {
+        let m = try_matrix!(
+            rows: 2,
+            cols: 2,
+            1, 2;
+            3, 4;
+            5, 6
+        );
+
+        assert_eq!(m, Err(MatrixError::IncorrectDataSize));
+    }
+
+    #[test]
+    fn try_matrix_ragged_rows() {
+        let cases = vec![
+            try_matrix![
+                1, 2;
+                3
+            ],
+            try_matrix![
+                1, 2;
+                3;
+                4, 5, 6
+            ],
+        ];
+
+        for m in cases {
+            assert_eq!(m, Err(MatrixError::IncorrectDataSize));
+        }
+    }
+
+    #[test]
+    fn col_vector() {
+        let m = col_vector![1, 2, 3];
+
+        assert_eq!(m.rows, 3);
+        assert_eq!(m.columns, 1);
+        assert_eq!(m.get(2, 0), Ok(3.0));
+        assert_eq!(m.data, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn row_vector() {
+        let m = row_vector![1.5, 2.5, 3.5, 4.5];
+
+        assert_eq!(m.rows, 1);
+        assert_eq!(m.columns, 4);
+        assert_eq!(m.get(0, 3), Ok(4.5));
+        assert_eq!(m.data, vec![1.5, 2.5, 3.5, 4.5]);
+    }
+
+    #[test]
+    fn approx_matrix_equal() {
+        let a = matrix![1.0, 2.0; 3.0, 4.0];
+        let b = matrix![1.0, 2.0000001; 3.0, 4.0];
+
+        approx_matrix!(a, b, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrices differ at (1, 0): 3 vs 3.5")]
+    fn approx_matrix_mismatch() {
+        let a = matrix![1.0, 2.0; 3.0, 4.0];
+        let b = matrix![1.0, 2.0; 3.5, 4.0];
+
+        approx_matrix!(a, b, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix shapes differ: 2x2 vs 1x4")]
+    fn approx_matrix_shape_mismatch() {
+        let a = matrix![1.0, 2.0; 3.0, 4.0];
+        let b = row_vector![1.0, 2.0, 3.0, 4.0];
+
+        approx_matrix!(a, b, 1e-6);
+    }
+
+    // The macros must expand without the caller importing `Matrix` or `MatrixError`
+    mod unimported {
+        #[test]
+        fn try_matrix() {
+            let sized = try_matrix!(
+                rows: 1,
+                cols: 2,
+                1, 2
+            );
+            let inferred = try_matrix![1, 2; 3];
+
+            assert!(sized.is_ok());
+            assert!(inferred.is_err());
+        }
+
+        #[test]
+        fn vectors() {
+            let column = col_vector![1, 2, 3];
+            let row = row_vector![1, 2, 3];
+
+            assert_eq!((column.rows, column.columns), (3, 1));
+            assert_eq!((row.rows, row.columns), (1, 3));
+        }
+
+        #[test]
+        fn approx_matrix() {
+            approx_matrix!(matrix![1.0, 2.0], row_vector![1.0, 2.0000001], 1e-6);
+        }
+    }
+}
@@ -1,24 +1,48 @@
 mod approx_eq;
 mod classification;
+mod conversion;
 mod core;
 mod display;
+mod exponential;
 mod operations;
+mod orthogonal;
+#[cfg(feature = "random")]
+mod random;
+mod reduction;
+mod solve;
+mod statistics;
 
 #[macro_use]
 mod matrix_macro;
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq)]
 pub struct Matrix {
     pub rows: usize,
     pub columns: usize,
     pub data: Vec<f64>,
 }
 
+/// An elementary row operation, as recorded by `Matrix::row_reduce_steps`.
+#[derive(Debug, PartialEq)]
+pub enum RowOp {
+    Swap(usize, usize),
+    Scale(usize, f64),
+    AddScaled {
+        target: usize,
+        source: usize,
+        factor: f64,
+    },
+}
+
 #[derive(Debug, PartialEq)]
 pub enum MatrixError {
     IncorrectDataSize,
     IncompatibleDimensions,
     SquareMatrixRequired,
+    Singular,
+    LinearlyDependent,
+    NotAffine,
+    NonFinite,
     InvalidIndex(usize, usize),
 }
 impl Matrix {
@@ -14,7 +14,7 @@ impl Matrix {
     }
 
     pub fn multiply(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
-        if self.rows != other.columns || self.columns != other.rows {
+        if self.columns != other.rows {
             return Err(MatrixError::IncompatibleDimensions);
         }
 
@@ -36,11 +36,133 @@ impl Matrix {
         Ok(result)
     }
 
+    /// Exponentiation by squaring; `pow(0)` is the identity.
+    pub fn pow(&self, exp: u32) -> Result<Matrix, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+
+        let mut result = Matrix::identity(self.rows);
+        let mut base = Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.clone(),
+        };
+        let mut exp = exp;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result.multiply(&base)?;
+            }
+            base = base.multiply(&base)?;
+            exp /= 2;
+        }
+
+        Ok(result)
+    }
+
+    /// Negative exponents raise the inverse, so singular matrices give `Singular` for them.
+    pub fn pow_signed(&self, exp: i32) -> Result<Matrix, MatrixError> {
+        if exp < 0 {
+            return self.inverse()?.pow(exp.unsigned_abs());
+        }
+
+        self.pow(exp.unsigned_abs())
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut result = Matrix::zeros(self.columns, self.rows);
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let index = result.get_index_ok(column, row);
+                result.data[index] = self.data[self.get_index_ok(row, column)];
+            }
+        }
+
+        result
+    }
+
+    /// `AᵀA`: the symmetric matrix of pairwise column dot products. Computed in place without
+    /// forming `Aᵀ`, filling the upper triangle and mirroring it.
+    pub fn gram(&self) -> Matrix {
+        let n = self.columns;
+        let mut result = Matrix::square_zeros(n);
+        for i in 0..n {
+            for j in i..n {
+                let dot: f64 = (0..self.rows)
+                    .map(|k| {
+                        self.data[self.get_index_ok(k, i)] * self.data[self.get_index_ok(k, j)]
+                    })
+                    .sum();
+                let upper = result.get_index_ok(i, j);
+                let lower = result.get_index_ok(j, i);
+                result.data[upper] = dot;
+                result.data[lower] = dot;
+            }
+        }
+
+        result
+    }
+
+    /// `AAᵀ`: the symmetric matrix of pairwise row dot products, computed like `gram`.
+    pub fn gram_transpose(&self) -> Matrix {
+        let n = self.rows;
+        let mut result = Matrix::square_zeros(n);
+        for i in 0..n {
+            for j in i..n {
+                let dot: f64 = (0..self.columns)
+                    .map(|k| {
+                        self.data[self.get_index_ok(i, k)] * self.data[self.get_index_ok(j, k)]
+                    })
+                    .sum();
+                let upper = result.get_index_ok(i, j);
+                let lower = result.get_index_ok(j, i);
+                result.data[upper] = dot;
+                result.data[lower] = dot;
+            }
+        }
+
+        result
+    }
+
+    /// Computed as `(AᵀA)⁻¹Aᵀ`, which assumes full column rank; otherwise `AᵀA` is singular
+    /// and `MatrixError::Singular` is returned.
+    pub fn pseudo_inverse(&self) -> Result<Matrix, MatrixError> {
+        let transposed = self.transpose();
+
+        transposed.multiply(self)?.inverse()?.multiply(&transposed)
+    }
+
+    /// The maximum absolute row sum.
+    pub fn inf_norm(&self) -> f64 {
+        self.data
+            .chunks(self.columns.max(1))
+            .map(|row| row.iter().map(|v| v.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// Infinity-norm condition number; singular matrices report infinity.
+    pub fn condition_number(&self) -> Result<f64, MatrixError> {
+        match self.inverse() {
+            Ok(inverse) => Ok(self.inf_norm() * inverse.inf_norm()),
+            Err(MatrixError::Singular) => Ok(f64::INFINITY),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn determinant(&self) -> Result<f64, MatrixError> {
         if self.rows != self.columns {
             return Err(MatrixError::SquareMatrixRequired);
         }
 
+        // The empty product: a 0x0 matrix has determinant 1.
+        if self.rows == 0 {
+            return Ok(1.0);
+        }
+
+        if self.rows == 1 {
+            return Ok(self.data[0]);
+        }
+
         if self.rows == 2 {
             return Ok(self.data[0] * self.data[3] - self.data[1] * self.data[2]);
         }
@@ -78,6 +200,7 @@ impl Matrix {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::approx_eq::ApproxEq;
     use crate::matrix;
 
     #[test]
@@ -127,6 +250,24 @@ mod tests {
         assert_eq!(result.get(1, 1).expect("r00"), 154.0);
     }
 
+    #[test]
+    fn multiplication_by_column_vector() {
+        let m = matrix!(
+            rows: 2,
+            cols: 2,
+            1.0, 2.0;
+            3.0, 4.0
+        );
+        let v = Matrix::new(2, 1, vec![5.0, 6.0]).unwrap();
+
+        let result = m.multiply(&v).expect("Multiply should work");
+
+        assert_eq!(result.rows, 2);
+        assert_eq!(result.columns, 1);
+        assert_eq!(result.get(0, 0).expect("r00"), 17.0);
+        assert_eq!(result.get(1, 0).expect("r10"), 39.0);
+    }
+
     #[test]
     fn multiplication_error() {
         let m1 = Matrix::zeros(3, 2);
@@ -135,6 +276,32 @@ mod tests {
         m1.multiply(&m2).expect_err("Should be incompatible");
     }
 
+    #[test]
+    fn multiplication_non_square() {
+        let a = matrix!(
+            rows: 2,
+            cols: 3,
+            1, 2, 3;
+            4, 5, 6
+        );
+        let b = matrix!(
+            rows: 3,
+            cols: 4,
+            1, 0, 2, -1;
+            0, 1, 1, 2;
+            1, 1, 0, 3
+        );
+        let expected = matrix!(
+            rows: 2,
+            cols: 4,
+            4, 5, 4, 12;
+            10, 11, 13, 24
+        );
+
+        assert_eq!(a.multiply(&b), Ok(expected));
+        assert_eq!(b.multiply(&a), Err(MatrixError::IncompatibleDimensions));
+    }
+
     #[test]
     fn determinant() {
         struct Case {
@@ -228,4 +395,226 @@ mod tests {
 
         assert_eq!(result, MatrixError::SquareMatrixRequired);
     }
+
+    #[test]
+    fn inf_norm() {
+        let m = matrix!(
+            rows: 2,
+            cols: 3,
+            1, -7, 2;
+            -3, 4, 0
+        );
+
+        assert_eq!(m.inf_norm(), 10.0);
+    }
+
+    #[test]
+    fn condition_number() {
+        let nearly_singular = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 1;
+            1, 1.0001
+        );
+        let singular = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 2;
+            2, 4
+        );
+
+        assert_eq!(Matrix::identity(3).condition_number(), Ok(1.0));
+        assert!(nearly_singular.condition_number().unwrap() > 1e4);
+        assert_eq!(singular.condition_number(), Ok(f64::INFINITY));
+        assert!(
+            Matrix::from_diagonal(&[1e-11, 1e-11, 1e-11])
+                .condition_number()
+                .unwrap()
+                .approx_eq_default(&1.0)
+        );
+        assert_eq!(
+            Matrix::zeros(2, 3).condition_number(),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let m = matrix!(
+            rows: 2,
+            cols: 3,
+            1, 2, 3;
+            4, 5, 6
+        );
+        let expected = matrix!(
+            rows: 3,
+            cols: 2,
+            1, 4;
+            2, 5;
+            3, 6
+        );
+
+        assert_eq!(m.transpose(), expected);
+    }
+
+    #[test]
+    fn pseudo_inverse_least_squares() {
+        // Fit y = a + bx through (0, 1), (1, 2), (2, 2), (3, 4).
+        let design = matrix!(
+            rows: 4,
+            cols: 2,
+            1, 0;
+            1, 1;
+            1, 2;
+            1, 3
+        );
+        let observed = Matrix::new(4, 1, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+
+        let coefficients = design
+            .pseudo_inverse()
+            .unwrap()
+            .multiply(&observed)
+            .unwrap();
+
+        assert!(coefficients.approx_eq_default(&Matrix::new(2, 1, vec![0.9, 0.9]).unwrap()));
+    }
+
+    #[test]
+    fn pseudo_inverse_rank_deficient() {
+        let m = matrix!(
+            rows: 3,
+            cols: 2,
+            1, 2;
+            2, 4;
+            3, 6
+        );
+
+        assert_eq!(m.pseudo_inverse(), Err(MatrixError::Singular));
+    }
+
+    #[test]
+    fn determinant_tiny() {
+        let one_by_one = Matrix::new(1, 1, vec![-4.5]).unwrap();
+
+        assert_eq!(one_by_one.determinant(), Ok(-4.5));
+        assert_eq!(Matrix::zeros(0, 0).determinant(), Ok(1.0));
+    }
+
+    #[test]
+    fn pow() {
+        let m = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 1;
+            1, 0
+        );
+        let fibonacci = matrix!(
+            rows: 2,
+            cols: 2,
+            8, 5;
+            5, 3
+        );
+
+        assert_eq!(m.pow(0), Ok(Matrix::identity(2)));
+        assert_eq!(m.pow(5), Ok(fibonacci));
+        assert_eq!(
+            Matrix::zeros(2, 3).pow(2),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+
+    #[test]
+    fn pow_signed() {
+        let m = matrix!(
+            rows: 2,
+            cols: 2,
+            2, 1;
+            1, 1
+        );
+
+        let inverse = m.pow_signed(-1).unwrap();
+        let round_trip = m
+            .pow_signed(-2)
+            .unwrap()
+            .multiply(&m.pow_signed(2).unwrap())
+            .unwrap();
+
+        assert!(inverse.approx_eq_default(&m.inverse().unwrap()));
+        assert!(round_trip.approx_eq_default(&Matrix::identity(2)));
+    }
+
+    #[test]
+    fn pow_signed_singular() {
+        let singular = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 2;
+            2, 4
+        );
+
+        assert_eq!(singular.pow_signed(-1), Err(MatrixError::Singular));
+        assert!(singular.pow_signed(2).is_ok());
+    }
+
+    #[test]
+    fn gram() {
+        let s = 1.0 / 2f64.sqrt();
+        let orthonormal = matrix!(
+            rows: 3,
+            cols: 2,
+            s, s;
+            s, -s;
+            0, 0
+        );
+        let m = matrix!(
+            rows: 3,
+            cols: 2,
+            1, 2;
+            0, 1;
+            3, -1
+        );
+        let expected = matrix!(
+            rows: 2,
+            cols: 2,
+            10, -1;
+            -1, 6
+        );
+
+        assert!(orthonormal.gram().approx_eq_default(&Matrix::identity(2)));
+        assert!(m.gram().approx_eq_default(&expected));
+    }
+
+    #[test]
+    fn gram_matches_naive_product() {
+        let cases = vec![
+            matrix!(
+                rows: 4,
+                cols: 3,
+                1.5, -2, 0.25;
+                3, 0, -1;
+                -0.5, 4, 2;
+                2, 1, -3
+            ),
+            matrix!(
+                rows: 2,
+                cols: 5,
+                1, 2, 3, 4, 5;
+                -1, 0.5, 2, -3, 7
+            ),
+            matrix!(
+                rows: 1,
+                cols: 1,
+                3
+            ),
+        ];
+
+        for m in cases.into_iter() {
+            let transposed = m.transpose();
+            let naive_gram = transposed.multiply(&m).unwrap();
+            let naive_gram_transpose = m.multiply(&transposed).unwrap();
+
+            assert!(m.gram().approx_eq_default(&naive_gram));
+            assert!(m.gram_transpose().approx_eq_default(&naive_gram_transpose));
+        }
+    }
 }
@@ -0,0 +1,157 @@
+use super::{Matrix, MatrixError};
+
+const DEPENDENCE_EPSILON: f64 = 1e-10;
+
+fn norm(values: &[f64]) -> f64 {
+    values.iter().map(|v| v * v).sum::<f64>().sqrt()
+}
+
+impl Matrix {
+    /// Modified Gram-Schmidt over the columns, left to right. A column counts as dependent when
+    /// its residual is below `DEPENDENCE_EPSILON` of its original norm, so scale doesn't matter.
+    pub fn orthonormalize_columns(&self) -> Result<Matrix, MatrixError> {
+        let mut columns: Vec<Vec<f64>> = (0..self.columns)
+            .map(|column| {
+                (0..self.rows)
+                    .map(|row| self.data[self.get_index_ok(row, column)])
+                    .collect()
+            })
+            .collect();
+        let original_norms: Vec<f64> = columns.iter().map(|column| norm(column)).collect();
+
+        for i in 0..columns.len() {
+            let residual = norm(&columns[i]);
+            if residual <= original_norms[i] * DEPENDENCE_EPSILON {
+                return Err(MatrixError::LinearlyDependent);
+            }
+            columns[i].iter_mut().for_each(|v| *v /= residual);
+
+            let (done, rest) = columns.split_at_mut(i + 1);
+            let basis = &done[i];
+            for column in rest.iter_mut() {
+                let projection: f64 = basis.iter().zip(column.iter()).map(|(a, b)| a * b).sum();
+                for (value, b) in column.iter_mut().zip(basis.iter()) {
+                    *value -= projection * b;
+                }
+            }
+        }
+
+        let mut result = Matrix::zeros(self.rows, self.columns);
+        for (column, values) in columns.iter().enumerate() {
+            for (row, value) in values.iter().enumerate() {
+                let index = result.get_index_ok(row, column);
+                result.data[index] = *value;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::matrix;
+
+    fn column(m: &Matrix, column: usize) -> Vec<f64> {
+        (0..m.rows).map(|row| m.get(row, column).unwrap()).collect()
+    }
+
+    fn dot(a: &[f64], b: &[f64]) -> f64 {
+        a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn orthonormalize_columns() {
+        let m = matrix!(
+            rows: 3,
+            cols: 3,
+            1, 1, 0;
+            1, 0, 1;
+            0, 1, 1
+        );
+
+        let result = m.orthonormalize_columns().expect("columns are independent");
+
+        for i in 0..3 {
+            let a = column(&result, i);
+            assert!(dot(&a, &a).approx_eq_default(&1.0));
+
+            for j in (i + 1)..3 {
+                assert!(dot(&a, &column(&result, j)).approx_eq_default(&0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn orthonormalize_keeps_first_direction() {
+        let m = matrix!(
+            rows: 2,
+            cols: 2,
+            3, 1;
+            4, 1
+        );
+
+        let result = m.orthonormalize_columns().unwrap();
+
+        assert!(column(&result, 0).approx_eq_default(&vec![0.6, 0.8]));
+    }
+
+    #[test]
+    fn orthonormalize_dependent_columns() {
+        let m = matrix!(
+            rows: 3,
+            cols: 2,
+            1, 2;
+            2, 4;
+            3, 6
+        );
+
+        assert_eq!(
+            m.orthonormalize_columns(),
+            Err(MatrixError::LinearlyDependent)
+        );
+    }
+
+    #[test]
+    fn orthonormalize_small_scale() {
+        let m = matrix!(
+            rows: 2,
+            cols: 2,
+            3e-12, 1e-12;
+            4e-12, 1e-12
+        );
+
+        let result = m.orthonormalize_columns().expect("columns are independent");
+
+        assert!(column(&result, 0).approx_eq_default(&vec![0.6, 0.8]));
+        assert!(dot(&column(&result, 1), &column(&result, 1)).approx_eq_default(&1.0));
+        assert!(dot(&column(&result, 0), &column(&result, 1)).approx_eq_default(&0.0));
+    }
+
+    #[test]
+    fn orthonormalize_nearly_dependent_large_columns() {
+        let m = matrix!(
+            rows: 2,
+            cols: 2,
+            1e6, 1e6;
+            0, 1e-6
+        );
+        let zero_column = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 0;
+            0, 0
+        );
+
+        assert_eq!(
+            m.orthonormalize_columns(),
+            Err(MatrixError::LinearlyDependent)
+        );
+        assert_eq!(
+            zero_column.orthonormalize_columns(),
+            Err(MatrixError::LinearlyDependent)
+        );
+    }
+}
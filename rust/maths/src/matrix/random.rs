@@ -0,0 +1,29 @@
+use super::Matrix;
+use crate::random::Rng;
+
+impl Matrix {
+    /// Entries are uniform in `[0, 1)` and fully determined by `seed`.
+    pub fn random(rows: usize, columns: usize, seed: u64) -> Matrix {
+        let mut rng = Rng::new(seed);
+
+        Matrix {
+            rows,
+            columns,
+            data: (0..rows * columns).map(|_| rng.next_f64()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_is_deterministic() {
+        let a = Matrix::random(3, 4, 99);
+
+        assert_eq!(a, Matrix::random(3, 4, 99));
+        assert_ne!(a, Matrix::random(3, 4, 100));
+        assert!(a.data.iter().all(|v| (0.0..1.0).contains(v)));
+    }
+}
@@ -0,0 +1,602 @@
+use super::{Matrix, MatrixError, RowOp};
+
+impl Matrix {
+    /// Reduced row echelon form, using partial pivoting and snapping entries that are negligible
+    /// relative to the matrix norm to zero.
+    pub fn rref(&self) -> Matrix {
+        let tolerance = self.pivot_tolerance();
+        let (mut m, _, _) = self.reduce(tolerance);
+
+        for value in m.data.iter_mut() {
+            if value.abs() <= tolerance {
+                *value = 0.0;
+            }
+        }
+
+        m
+    }
+
+    /// Performs the same reduction as `rref`, without snapping near-zero entries, and returns
+    /// the elementary operations applied in order. Replaying them with `apply_row_op` on a copy
+    /// of `self` reproduces the result.
+    pub fn row_reduce_steps(&self) -> (Matrix, Vec<RowOp>) {
+        let (m, steps, _) = self.reduce(self.pivot_tolerance());
+
+        (m, steps)
+    }
+
+    /// Rounding noise left by elimination scales with the matrix, so pivots are judged against
+    /// its infinity norm rather than an absolute cutoff.
+    fn pivot_tolerance(&self) -> f64 {
+        self.inf_norm() * self.rows.max(self.columns) as f64 * f64::EPSILON
+    }
+
+    /// Gauss-Jordan elimination, skipping columns whose best pivot is within `tolerance` of zero.
+    /// Also returns the pivot columns in order.
+    fn reduce(&self, tolerance: f64) -> (Matrix, Vec<RowOp>, Vec<usize>) {
+        let mut m = Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.clone(),
+        };
+        let mut steps = Vec::new();
+        let mut pivot_columns = Vec::new();
+        let mut record = |m: &mut Matrix, op: RowOp| {
+            m.apply_row_op(&op);
+            steps.push(op);
+        };
+
+        let mut pivot_row = 0;
+        for column in 0..m.columns {
+            if pivot_row == m.rows {
+                break;
+            }
+
+            let best = (pivot_row..m.rows)
+                .max_by(|a, b| {
+                    let a = m.data[m.get_index_ok(*a, column)].abs();
+                    let b = m.data[m.get_index_ok(*b, column)].abs();
+                    a.total_cmp(&b)
+                })
+                .expect("pivot search range is not empty");
+            if m.data[m.get_index_ok(best, column)].abs() <= tolerance {
+                continue;
+            }
+            if best != pivot_row {
+                record(&mut m, RowOp::Swap(pivot_row, best));
+            }
+
+            let pivot = m.data[m.get_index_ok(pivot_row, column)];
+            if pivot != 1.0 {
+                record(&mut m, RowOp::Scale(pivot_row, 1.0 / pivot));
+            }
+
+            for row in 0..m.rows {
+                if row == pivot_row {
+                    continue;
+                }
+                let factor = m.data[m.get_index_ok(row, column)];
+                if factor == 0.0 {
+                    continue;
+                }
+                record(
+                    &mut m,
+                    RowOp::AddScaled {
+                        target: row,
+                        source: pivot_row,
+                        factor: -factor,
+                    },
+                );
+            }
+
+            pivot_columns.push(column);
+            pivot_row += 1;
+        }
+
+        (m, steps, pivot_columns)
+    }
+
+    pub fn apply_row_op(&mut self, op: &RowOp) {
+        match *op {
+            RowOp::Swap(a, b) => self.swap_rows(a, b),
+            RowOp::Scale(row, factor) => {
+                for c in 0..self.columns {
+                    let index = self.get_index_ok(row, c);
+                    self.data[index] *= factor;
+                }
+            }
+            RowOp::AddScaled {
+                target,
+                source,
+                factor,
+            } => {
+                for c in 0..self.columns {
+                    let value = self.data[self.get_index_ok(source, c)];
+                    let index = self.get_index_ok(target, c);
+                    self.data[index] += factor * value;
+                }
+            }
+        }
+    }
+
+    /// Gauss-Jordan elimination on `[A | I]`.
+    pub fn inverse(&self) -> Result<Matrix, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+
+        let n = self.rows;
+        let mut augmented = Matrix::zeros(n, 2 * n);
+        for row in 0..n {
+            for column in 0..n {
+                let index = augmented.get_index_ok(row, column);
+                augmented.data[index] = self.data[self.get_index_ok(row, column)];
+            }
+            let index = augmented.get_index_ok(row, n + row);
+            augmented.data[index] = 1.0;
+        }
+
+        // Judge pivots against A alone; the identity half would swamp the norm of a tiny A
+        let (reduced, _, pivot_columns) = augmented.reduce(self.pivot_tolerance());
+        if pivot_columns
+            .iter()
+            .take_while(|column| **column < n)
+            .count()
+            < n
+        {
+            return Err(MatrixError::Singular);
+        }
+
+        let mut result = Matrix::square_zeros(n);
+        for row in 0..n {
+            for column in 0..n {
+                let index = result.get_index_ok(row, column);
+                result.data[index] = reduced.data[reduced.get_index_ok(row, n + column)];
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Sign of the determinant from Gaussian elimination, tracking row swaps and pivot signs
+    /// rather than multiplying the pivots out. Pivots negligible against the matrix norm count
+    /// as singular.
+    pub fn determinant_sign(&self) -> Result<i32, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+
+        Ok(match self.elimination_pivots(self.pivot_tolerance()) {
+            Some((pivots, swap_sign)) => pivots
+                .iter()
+                .fold(swap_sign, |sign, p| if *p < 0.0 { -sign } else { sign }),
+            None => 0,
+        })
+    }
+
+    /// Natural log of `|det|` and the determinant's sign, summed from the elimination pivots so
+    /// large matrices cannot overflow. A singular matrix gives `(-inf, 0)`.
+    pub fn log_determinant(&self) -> Result<(f64, i32), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+
+        Ok(match self.elimination_pivots(self.pivot_tolerance()) {
+            Some((pivots, swap_sign)) => pivots.iter().fold((0.0, swap_sign), |(log, sign), p| {
+                (log + p.abs().ln(), if *p < 0.0 { -sign } else { sign })
+            }),
+            None => (f64::NEG_INFINITY, 0),
+        })
+    }
+
+    /// True for a square matrix whose determinant, taken from the elimination pivots, is
+    /// further than `eps` from zero. Only `eps` decides; no pivot is discarded as too small.
+    pub fn is_invertible(&self, eps: f64) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        match self.elimination_pivots(0.0) {
+            Some((pivots, swap_sign)) => {
+                (swap_sign as f64 * pivots.iter().product::<f64>()).abs() > eps
+            }
+            None => false,
+        }
+    }
+
+    /// Gaussian elimination with partial pivoting on a square matrix, returning the pivots and
+    /// the sign contributed by row swaps, or `None` once a pivot is within `tolerance` of zero.
+    fn elimination_pivots(&self, tolerance: f64) -> Option<(Vec<f64>, i32)> {
+        let mut m = Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.clone(),
+        };
+        let mut pivots = Vec::with_capacity(m.rows);
+        let mut swap_sign = 1;
+        for column in 0..m.columns {
+            let best = (column..m.rows)
+                .max_by(|a, b| {
+                    let a = m.data[m.get_index_ok(*a, column)].abs();
+                    let b = m.data[m.get_index_ok(*b, column)].abs();
+                    a.total_cmp(&b)
+                })
+                .expect("pivot search range is not empty");
+            if m.data[m.get_index_ok(best, column)].abs() <= tolerance {
+                return None;
+            }
+            if best != column {
+                m.swap_rows(column, best);
+                swap_sign = -swap_sign;
+            }
+
+            let pivot = m.data[m.get_index_ok(column, column)];
+            pivots.push(pivot);
+            for row in (column + 1)..m.rows {
+                let factor = m.data[m.get_index_ok(row, column)] / pivot;
+                for c in column..m.columns {
+                    let source = m.data[m.get_index_ok(column, c)];
+                    let index = m.get_index_ok(row, c);
+                    m.data[index] -= factor * source;
+                }
+            }
+        }
+
+        Some((pivots, swap_sign))
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for c in 0..self.columns {
+            let a_index = self.get_index_ok(a, c);
+            let b_index = self.get_index_ok(b, c);
+            self.data.swap(a_index, b_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::matrix;
+
+    #[test]
+    fn rref() {
+        struct Case {
+            matrix: Matrix,
+            expected: Matrix,
+        }
+
+        let cases = vec![
+            Case {
+                matrix: matrix!(
+                    rows: 2,
+                    cols: 2,
+                    1, 2;
+                    3, 4
+                ),
+                expected: Matrix::identity(2),
+            },
+            Case {
+                matrix: matrix!(
+                    rows: 3,
+                    cols: 3,
+                    1, 2, 3;
+                    4, 5, 6;
+                    7, 8, 9
+                ),
+                expected: matrix!(
+                    rows: 3,
+                    cols: 3,
+                    1, 0, -1;
+                    0, 1, 2;
+                    0, 0, 0
+                ),
+            },
+            Case {
+                matrix: matrix!(
+                    rows: 2,
+                    cols: 4,
+                    0, 2, 4, 2;
+                    1, 1, 1, 1
+                ),
+                expected: matrix!(
+                    rows: 2,
+                    cols: 4,
+                    1, 0, -1, 0;
+                    0, 1, 2, 1
+                ),
+            },
+            Case {
+                matrix: Matrix::zeros(2, 3),
+                expected: Matrix::zeros(2, 3),
+            },
+        ];
+
+        for (i, case) in cases.into_iter().enumerate() {
+            let result = case.matrix.rref();
+
+            assert!(
+                result.approx_eq_default(&case.expected),
+                "case {} failed, got\n{}",
+                i,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn rref_small_scale() {
+        let m = matrix!(
+            rows: 2,
+            cols: 3,
+            2e-12, 4e-12, 2e-12;
+            1e-12, 3e-12, 2e-12
+        );
+        let expected = matrix!(
+            rows: 2,
+            cols: 3,
+            1, 0, -1;
+            0, 1, 1
+        );
+
+        assert!(m.rref().approx_eq_default(&expected));
+    }
+
+    #[test]
+    fn inverse() {
+        let m = matrix!(
+            rows: 3,
+            cols: 3,
+            2, 0, 1;
+            1, 3, 2;
+            1, 1, 2
+        );
+
+        let result = m.inverse().expect("matrix is invertible");
+
+        assert!(
+            result
+                .multiply(&m)
+                .unwrap()
+                .approx_eq_default(&Matrix::identity(3))
+        );
+        assert!(
+            m.multiply(&result)
+                .unwrap()
+                .approx_eq_default(&Matrix::identity(3))
+        );
+    }
+
+    #[test]
+    fn inverse_small_scale() {
+        let tiny = Matrix::from_diagonal(&[1e-11, 1e-11]);
+        let scaled = matrix!(
+            rows: 2,
+            cols: 2,
+            2e-12, 1e-12;
+            1e-12, 1e-12
+        );
+        let expected = matrix!(
+            rows: 2,
+            cols: 2,
+            1e12, -1e12;
+            -1e12, 2e12
+        );
+
+        assert!(
+            tiny.inverse()
+                .unwrap()
+                .approx_eq_rel(&Matrix::from_diagonal(&[1e11, 1e11]), 1e-12)
+        );
+        assert!(scaled.inverse().unwrap().approx_eq_rel(&expected, 1e-9));
+        assert_eq!(
+            Matrix::from_diagonal(&[1e-11, 0.0]).inverse(),
+            Err(MatrixError::Singular)
+        );
+    }
+
+    #[test]
+    fn inverse_errors() {
+        let singular = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 2;
+            2, 4
+        );
+
+        assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+        assert_eq!(
+            Matrix::square_zeros(2).inverse(),
+            Err(MatrixError::Singular)
+        );
+        assert_eq!(
+            Matrix::zeros(2, 3).inverse(),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+
+    #[test]
+    fn determinant_sign() {
+        struct SignCase {
+            matrix: Matrix,
+            expected: i32,
+        }
+
+        let cases: Vec<SignCase> = vec![
+            SignCase {
+                matrix: matrix!(
+                    rows: 3,
+                    cols: 3,
+                    0, 1, 0;
+                    -1, 0, 0;
+                    0, 0, 1
+                ),
+                expected: 1,
+            },
+            SignCase {
+                matrix: matrix!(
+                    rows: 3,
+                    cols: 3,
+                    -1, 0, 0;
+                    0, 1, 0;
+                    0, 0, 1
+                ),
+                expected: -1,
+            },
+            SignCase {
+                matrix: matrix!(
+                    rows: 3,
+                    cols: 3,
+                    1, 2, 3;
+                    4, 5, 6;
+                    7, 8, 9
+                ),
+                expected: 0,
+            },
+            SignCase {
+                matrix: matrix!(
+                    rows: 3,
+                    cols: 3,
+                    2, 0, 1;
+                    1, 3, 2;
+                    1, 1, 2
+                ),
+                expected: 1,
+            },
+        ];
+
+        for case in cases {
+            assert_eq!(case.matrix.determinant_sign(), Ok(case.expected));
+        }
+        assert_eq!(
+            Matrix::zeros(2, 3).determinant_sign(),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+
+    #[test]
+    fn is_invertible() {
+        let singular = matrix!(
+            rows: 3,
+            cols: 3,
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        );
+        let tiny = Matrix::from_diagonal(&[1e-4, 1e-4]);
+
+        assert!(Matrix::identity(3).is_invertible(1e-9));
+        assert!(!singular.is_invertible(1e-9));
+        assert!(!Matrix::zeros(2, 3).is_invertible(1e-9));
+        assert!(tiny.is_invertible(1e-9));
+        assert!(!tiny.is_invertible(1e-6));
+        assert!(Matrix::from_diagonal(&[1e-11, 1e20]).is_invertible(1e-30));
+        assert!(Matrix::from_diagonal(&[1e-11, 1e-11]).is_invertible(1e-30));
+        assert!(!Matrix::from_diagonal(&[1e-11, 1e20]).is_invertible(1e10));
+        assert!(!Matrix::from_diagonal(&[1e-11, 0.0]).is_invertible(0.0));
+    }
+
+    #[test]
+    fn row_reduce_steps_replay() {
+        let m = matrix!(
+            rows: 3,
+            cols: 4,
+            0, 2, 1, 4;
+            1, -1, 2, 3;
+            2, 0, 5, 1
+        );
+
+        let (reduced, steps) = m.row_reduce_steps();
+        let mut replayed = Matrix {
+            rows: m.rows,
+            columns: m.columns,
+            data: m.data.clone(),
+        };
+        for step in steps.iter() {
+            replayed.apply_row_op(step);
+        }
+
+        assert_eq!(steps[0], RowOp::Swap(0, 2));
+        assert_eq!(steps[1], RowOp::Scale(0, 0.5));
+        assert!(replayed.approx_eq_default(&reduced));
+        assert!(reduced.approx_eq_default(&m.rref()));
+    }
+
+    #[test]
+    fn apply_row_op() {
+        let mut m = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 2;
+            3, 4
+        );
+
+        m.apply_row_op(&RowOp::Swap(0, 1));
+        m.apply_row_op(&RowOp::Scale(1, 3.0));
+        m.apply_row_op(&RowOp::AddScaled {
+            target: 0,
+            source: 1,
+            factor: -1.0,
+        });
+
+        assert_eq!(m, Matrix::new(2, 2, vec![0.0, -2.0, 3.0, 6.0]).unwrap());
+    }
+
+    #[test]
+    fn log_determinant() {
+        let m = matrix!(
+            rows: 6,
+            cols: 6,
+            4, -2, 1, 3, 0, 2;
+            1, 5, -3, 2, 1, 0;
+            -2, 1, 6, 0, 3, 1;
+            3, 0, 2, -7, 1, 4;
+            0, 2, 1, 1, 8, -3;
+            2, -1, 0, 3, 2, 5
+        );
+        let singular = matrix!(
+            rows: 3,
+            cols: 3,
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        );
+
+        let (log_det, sign) = m.log_determinant().unwrap();
+        let expected = m.determinant().unwrap();
+
+        assert!((log_det.exp() * sign as f64).approx_eq_rel(&expected, 1e-9));
+        assert_eq!(sign, m.determinant_sign().unwrap());
+        let (huge_log_det, huge_sign) = Matrix::from_diagonal(&[1e200, 1e200, -1e200])
+            .log_determinant()
+            .unwrap();
+        assert!(huge_log_det.approx_eq_rel(&(600.0 * 10f64.ln()), 1e-12));
+        assert_eq!(huge_sign, -1);
+        assert_eq!(singular.log_determinant(), Ok((f64::NEG_INFINITY, 0)));
+
+        let (tiny_log_det, tiny_sign) = Matrix::from_diagonal(&[1e-20, 1e-20, 1e-20])
+            .log_determinant()
+            .unwrap();
+        assert!(tiny_log_det.approx_eq_rel(&(-60.0 * 10f64.ln()), 1e-12));
+        assert_eq!(tiny_sign, 1);
+
+        let scaled = matrix!(
+            rows: 2,
+            cols: 2,
+            -2e-20, 1e-20;
+            1e-20, 1e-20
+        );
+        let (scaled_log_det, scaled_sign) = scaled.log_determinant().unwrap();
+        assert!(scaled_log_det.approx_eq_rel(&(3e-40f64).ln(), 1e-12));
+        assert_eq!(scaled_sign, -1);
+        assert_eq!(scaled.determinant_sign(), Ok(-1));
+        assert_eq!(
+            Matrix::zeros(2, 3).log_determinant(),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+}
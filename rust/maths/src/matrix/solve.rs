@@ -0,0 +1,107 @@
+use super::{Matrix, MatrixError};
+
+impl Matrix {
+    /// Solves `Ly = b` for a lower-triangular `l`; entries above the diagonal are ignored.
+    pub fn forward_substitution(l: &Matrix, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        Matrix::check_triangular_system(l, b)?;
+
+        let mut y = vec![0.0; b.len()];
+        for row in 0..l.rows {
+            let sum: f64 = (0..row)
+                .map(|column| l.data[l.get_index_ok(row, column)] * y[column])
+                .sum();
+            y[row] = (b[row] - sum) / l.data[l.get_index_ok(row, row)];
+        }
+
+        Ok(y)
+    }
+
+    /// Solves `Ux = y` for an upper-triangular `u`; entries below the diagonal are ignored.
+    pub fn back_substitution(u: &Matrix, y: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        Matrix::check_triangular_system(u, y)?;
+
+        let mut x = vec![0.0; y.len()];
+        for row in (0..u.rows).rev() {
+            let sum: f64 = ((row + 1)..u.columns)
+                .map(|column| u.data[u.get_index_ok(row, column)] * x[column])
+                .sum();
+            x[row] = (y[row] - sum) / u.data[u.get_index_ok(row, row)];
+        }
+
+        Ok(x)
+    }
+
+    fn check_triangular_system(m: &Matrix, rhs: &[f64]) -> Result<(), MatrixError> {
+        if !m.is_square() {
+            return Err(MatrixError::SquareMatrixRequired);
+        }
+        if rhs.len() != m.rows {
+            return Err(MatrixError::IncompatibleDimensions);
+        }
+        if (0..m.rows).any(|i| m.data[m.get_index_ok(i, i)] == 0.0) {
+            return Err(MatrixError::Singular);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::matrix;
+
+    #[test]
+    fn forward_substitution() {
+        let l = matrix!(
+            rows: 3,
+            cols: 3,
+            2, 0, 0;
+            1, 3, 0;
+            -1, 2, 4
+        );
+
+        let result = Matrix::forward_substitution(&l, &[4.0, 11.0, 15.0]).unwrap();
+
+        assert!(result.approx_eq_default(&vec![2.0, 3.0, 2.75]));
+    }
+
+    #[test]
+    fn back_substitution() {
+        let u = matrix!(
+            rows: 3,
+            cols: 3,
+            1, 2, -1;
+            0, 2, 1;
+            0, 0, 5
+        );
+
+        let result = Matrix::back_substitution(&u, &[2.0, 7.0, 10.0]).unwrap();
+
+        assert!(result.approx_eq_default(&vec![-1.0, 2.5, 2.0]));
+    }
+
+    #[test]
+    fn substitution_errors() {
+        let zero_pivot = matrix!(
+            rows: 2,
+            cols: 2,
+            1, 0;
+            3, 0
+        );
+
+        assert_eq!(
+            Matrix::forward_substitution(&zero_pivot, &[1.0, 2.0]),
+            Err(MatrixError::Singular)
+        );
+        assert_eq!(
+            Matrix::back_substitution(&Matrix::identity(3), &[1.0, 2.0]),
+            Err(MatrixError::IncompatibleDimensions)
+        );
+        assert_eq!(
+            Matrix::back_substitution(&Matrix::zeros(2, 3), &[1.0, 2.0]),
+            Err(MatrixError::SquareMatrixRequired)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use super::{Matrix, MatrixError};
+
+const ZERO_VARIANCE_EPSILON: f64 = 1e-12;
+
+impl Matrix {
+    pub fn column_means(&self) -> Vec<f64> {
+        (0..self.columns)
+            .map(|column| {
+                (0..self.rows)
+                    .map(|row| self.data[self.get_index_ok(row, column)])
+                    .sum::<f64>()
+                    / self.rows as f64
+            })
+            .collect()
+    }
+
+    /// Centres each column on zero and scales it by its population standard deviation.
+    /// Constant columns are only centred, leaving them as zeros.
+    pub fn normalize_columns(&self) -> Matrix {
+        let means = self.column_means();
+        let deviations: Vec<f64> = (0..self.columns)
+            .map(|column| {
+                let variance = (0..self.rows)
+                    .map(|row| (self.data[self.get_index_ok(row, column)] - means[column]).powi(2))
+                    .sum::<f64>()
+                    / self.rows as f64;
+                let deviation = variance.sqrt();
+
+                if deviation < ZERO_VARIANCE_EPSILON {
+                    1.0
+                } else {
+                    deviation
+                }
+            })
+            .collect();
+
+        Matrix::from_fn(self.rows, self.columns, |row, column| {
+            (self.data[self.get_index_ok(row, column)] - means[column]) / deviations[column]
+        })
+    }
+
+    /// Sample covariance (divides by `n - 1`) where each inner vec is one observation. Needs at
+    /// least two observations of equal length, otherwise `IncorrectDataSize`.
+    pub fn covariance(samples: &[Vec<f64>]) -> Result<Matrix, MatrixError> {
+        if samples.len() < 2 {
+            return Err(MatrixError::IncorrectDataSize);
+        }
+        let dimension = samples[0].len();
+        if samples.iter().any(|s| s.len() != dimension) {
+            return Err(MatrixError::IncorrectDataSize);
+        }
+
+        let count = samples.len() as f64;
+        let means: Vec<f64> = (0..dimension)
+            .map(|i| samples.iter().map(|s| s[i]).sum::<f64>() / count)
+            .collect();
+
+        Ok(Matrix::from_fn(dimension, dimension, |row, column| {
+            samples
+                .iter()
+                .map(|s| (s[row] - means[row]) * (s[column] - means[column]))
+                .sum::<f64>()
+                / (count - 1.0)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::matrix;
+
+    #[test]
+    fn covariance() {
+        let samples = vec![vec![2.0, 1.0], vec![0.0, 3.0], vec![4.0, 5.0]];
+        let expected = matrix!(
+            rows: 2,
+            cols: 2,
+            4, 2;
+            2, 4
+        );
+
+        let result = Matrix::covariance(&samples).unwrap();
+
+        assert!(result.approx_eq_default(&expected));
+    }
+
+    #[test]
+    fn covariance_errors() {
+        let ragged = vec![vec![1.0, 2.0], vec![3.0]];
+
+        assert_eq!(
+            Matrix::covariance(&ragged),
+            Err(MatrixError::IncorrectDataSize)
+        );
+        assert_eq!(
+            Matrix::covariance(&[vec![1.0, 2.0]]),
+            Err(MatrixError::IncorrectDataSize)
+        );
+    }
+
+    #[test]
+    fn column_means() {
+        let m = matrix!(
+            rows: 3,
+            cols: 2,
+            1, 10;
+            2, 20;
+            6, 0
+        );
+
+        assert!(m.column_means().approx_eq_default(&vec![3.0, 10.0]));
+    }
+
+    #[test]
+    fn normalize_columns() {
+        let m = matrix!(
+            rows: 4,
+            cols: 3,
+            1, 7, 5;
+            2, -3, 5;
+            3, 0, 5;
+            10, 4, 5
+        );
+
+        let result = m.normalize_columns();
+
+        assert!(result.data.iter().all(|v| v.is_finite()));
+        assert!(
+            result
+                .column_means()
+                .approx_eq_default(&vec![0.0, 0.0, 0.0])
+        );
+        for column in 0..2 {
+            let variance: f64 = (0..4)
+                .map(|row| result.get(row, column).unwrap().powi(2))
+                .sum::<f64>()
+                / 4.0;
+            assert!(variance.approx_eq_default(&1.0));
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::matrix::Matrix;
+use crate::vector2::Vector2;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Connectivity {
+    Four,
+    /// Diagonal steps cost `sqrt(2)` and may not cut the corner of an obstacle.
+    Eight,
+}
+
+struct Candidate {
+    estimate: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    // Reversed so the max-heap pops the lowest estimate first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+fn is_open(grid: &Matrix, (row, column): (usize, usize)) -> bool {
+    grid.get(row, column).is_ok_and(|v| v == 0.0)
+}
+
+fn to_point((row, column): (usize, usize)) -> Vector2 {
+    Vector2::new(column as f64, row as f64)
+}
+
+/// Returns the cells from `start` to `goal` inclusive, as `(row, column)`. Any nonzero cell is an
+/// obstacle; blocked or out-of-range endpoints give `None`.
+pub fn astar(
+    grid: &Matrix,
+    start: (usize, usize),
+    goal: (usize, usize),
+    connectivity: Connectivity,
+) -> Option<Vec<(usize, usize)>> {
+    if !is_open(grid, start) || !is_open(grid, goal) {
+        return None;
+    }
+
+    let cell = |index: usize| (index / grid.columns, index % grid.columns);
+    let index = |(row, column): (usize, usize)| row * grid.columns + column;
+    let goal_point = to_point(goal);
+    let steps: &[(isize, isize)] = match connectivity {
+        Connectivity::Four => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+        Connectivity::Eight => &[
+            (-1, 0),
+            (1, 0),
+            (0, -1),
+            (0, 1),
+            (-1, -1),
+            (-1, 1),
+            (1, -1),
+            (1, 1),
+        ],
+    };
+
+    let mut cost = vec![f64::INFINITY; grid.data.len()];
+    let mut came_from: Vec<Option<usize>> = vec![None; grid.data.len()];
+    let mut open = BinaryHeap::new();
+
+    cost[index(start)] = 0.0;
+    open.push(Candidate {
+        estimate: to_point(start).distance(&goal_point),
+        index: index(start),
+    });
+
+    while let Some(Candidate { index: current, .. }) = open.pop() {
+        if current == index(goal) {
+            let mut path = vec![goal];
+            let mut at = current;
+            while let Some(previous) = came_from[at] {
+                path.push(cell(previous));
+                at = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        let (row, column) = cell(current);
+        for (d_row, d_column) in steps {
+            let (Some(next_row), Some(next_column)) = (
+                row.checked_add_signed(*d_row),
+                column.checked_add_signed(*d_column),
+            ) else {
+                continue;
+            };
+            let next = (next_row, next_column);
+            if !is_open(grid, next) {
+                continue;
+            }
+
+            let diagonal = *d_row != 0 && *d_column != 0;
+            if diagonal && !(is_open(grid, (next_row, column)) && is_open(grid, (row, next_column)))
+            {
+                continue;
+            }
+
+            let step_cost = if diagonal { 2f64.sqrt() } else { 1.0 };
+            let next_cost = cost[current] + step_cost;
+            if next_cost < cost[index(next)] {
+                cost[index(next)] = next_cost;
+                came_from[index(next)] = Some(current);
+                open.push(Candidate {
+                    estimate: next_cost + to_point(next).distance(&goal_point),
+                    index: index(next),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn walled_grid() -> Matrix {
+        matrix!(
+            rows: 5,
+            cols: 5,
+            0, 0, 1, 0, 0;
+            0, 0, 1, 0, 0;
+            0, 0, 1, 0, 0;
+            0, 0, 1, 0, 0;
+            0, 0, 0, 0, 0
+        )
+    }
+
+    fn assert_valid_path(grid: &Matrix, path: &[(usize, usize)], connectivity: Connectivity) {
+        for pair in path.windows(2) {
+            let d_row = pair[0].0.abs_diff(pair[1].0);
+            let d_column = pair[0].1.abs_diff(pair[1].1);
+
+            assert!(d_row <= 1 && d_column <= 1 && d_row + d_column > 0);
+            if connectivity == Connectivity::Four {
+                assert_eq!(d_row + d_column, 1);
+            }
+        }
+        for (row, column) in path {
+            assert_eq!(grid.get(*row, *column), Ok(0.0));
+        }
+    }
+
+    #[test]
+    fn detour_around_wall() {
+        let grid = walled_grid();
+
+        let path = astar(&grid, (0, 0), (0, 4), Connectivity::Four).expect("goal is reachable");
+
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(0, 4)));
+        assert_eq!(path.len(), 13);
+        assert!(path.contains(&(4, 2)));
+        assert_valid_path(&grid, &path, Connectivity::Four);
+    }
+
+    #[test]
+    fn eight_connected_detour() {
+        let grid = walled_grid();
+
+        let path = astar(&grid, (0, 0), (0, 4), Connectivity::Eight).expect("goal is reachable");
+
+        assert_eq!(path.len(), 11);
+        assert!(path.contains(&(4, 2)));
+        assert_valid_path(&grid, &path, Connectivity::Eight);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let mut grid = walled_grid();
+        grid.set(4, 2, 1.0).unwrap();
+
+        assert_eq!(astar(&grid, (0, 0), (0, 4), Connectivity::Eight), None);
+        assert_eq!(astar(&grid, (0, 0), (0, 2), Connectivity::Four), None);
+        assert_eq!(astar(&grid, (0, 0), (9, 9), Connectivity::Four), None);
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let grid = walled_grid();
+
+        assert_eq!(
+            astar(&grid, (3, 3), (3, 3), Connectivity::Four),
+            Some(vec![(3, 3)])
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::Plane;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Plane {
+    fn approx_eq(&self, other: &Plane, eps: f64) -> bool {
+        self.normal.approx_eq(&other.normal, eps) && self.d.approx_eq(&other.d, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.normal.max_abs().max(self.d.abs())
+    }
+}
@@ -0,0 +1,155 @@
+use super::{Plane, PlaneError};
+use crate::ray::Ray;
+use crate::vector3::Vector3;
+
+const COLLINEAR_EPSILON: f64 = 1e-12;
+const PARALLEL_EPSILON: f64 = 1e-12;
+
+impl Plane {
+    pub fn from_point_normal(point: &Vector3, normal: &Vector3) -> Plane {
+        let normal = normal.normalize();
+        let d = -normal.dot_product(point);
+
+        Plane { normal, d }
+    }
+
+    /// The normal follows the right-hand rule for the winding `a`, `b`, `c`.
+    pub fn from_three_points(a: &Vector3, b: &Vector3, c: &Vector3) -> Result<Plane, PlaneError> {
+        let normal = b.subtract(a).cross_product(&c.subtract(a));
+        if normal.magnitude_squared() < COLLINEAR_EPSILON {
+            return Err(PlaneError::CollinearPoints);
+        }
+
+        Ok(Plane::from_point_normal(a, &normal))
+    }
+
+    pub fn normal(&self) -> &Vector3 {
+        &self.normal
+    }
+
+    pub fn d(&self) -> f64 {
+        self.d
+    }
+
+    /// Positive on the side the normal points towards.
+    pub fn signed_distance(&self, p: &Vector3) -> f64 {
+        self.normal.dot_product(p) + self.d
+    }
+
+    /// Hits from either side count; rays parallel to the plane or pointing away from it miss.
+    pub fn ray_intersect(&self, ray: &Ray) -> Option<f64> {
+        let denominator = self.normal.dot_product(ray.direction());
+        if denominator.abs() < PARALLEL_EPSILON {
+            return None;
+        }
+
+        let t = -self.signed_distance(ray.origin()) / denominator;
+        if t < 0.0 {
+            return None;
+        }
+
+        Some(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn from_point_normal() {
+        let plane = Plane::from_point_normal(&Vector3::new(0, 2, 0), &Vector3::new(0, 4, 0));
+
+        assert!(plane.normal().approx_eq_default(&Vector3::new(0, 1, 0)));
+        assert!(plane.d().approx_eq_default(&-2.0));
+    }
+
+    #[test]
+    fn from_three_points() {
+        let plane = Plane::from_three_points(
+            &Vector3::new(1, 0, 0),
+            &Vector3::new(0, 1, 0),
+            &Vector3::new(0, 0, 1),
+        )
+        .expect("points are not collinear");
+
+        let root_3 = 3.0_f64.sqrt();
+        let expected = Plane::from_point_normal(&Vector3::new(1, 0, 0), &Vector3::new(1, 1, 1));
+        assert!(plane.approx_eq_default(&expected));
+        assert!(plane.d().approx_eq_default(&(-1.0 / root_3)));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let plane = Plane::from_three_points(
+            &Vector3::new(1, 0, 0),
+            &Vector3::new(0, 1, 0),
+            &Vector3::new(0, 0, 1),
+        )
+        .expect("points are not collinear");
+        let root_3 = 3.0_f64.sqrt();
+
+        let cases: Vec<(Vector3, f64)> = vec![
+            (Vector3::new(0, 0, 0), -1.0 / root_3),
+            (Vector3::new(1, 1, 1), 2.0 / root_3),
+            (Vector3::new(0.5, 0.5, 0), 0.0),
+        ];
+
+        for (point, expected) in cases {
+            let result = plane.signed_distance(&point);
+
+            assert!(result.approx_eq_default(&expected));
+        }
+    }
+
+    #[test]
+    fn collinear_points() {
+        let result = Plane::from_three_points(
+            &Vector3::new(0, 0, 0),
+            &Vector3::new(1, 1, 1),
+            &Vector3::new(3, 3, 3),
+        );
+
+        assert_eq!(result, Err(PlaneError::CollinearPoints));
+    }
+
+    #[test]
+    fn ray_intersect() {
+        let ground = Plane::from_point_normal(&Vector3::zero(), &Vector3::unit_y());
+
+        struct IntersectCase {
+            ray: Ray,
+            expected: Option<f64>,
+        }
+
+        let cases: Vec<IntersectCase> = vec![
+            IntersectCase {
+                ray: Ray::new(Vector3::new(3, 5, -2), Vector3::new(0, -1, 0)).unwrap(),
+                expected: Some(5.0),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, -2, 0), Vector3::new(0, 1, 1)).unwrap(),
+                expected: Some(8f64.sqrt()),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, 5, 0), Vector3::new(1, 0, 0)).unwrap(),
+                expected: None,
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, 5, 0), Vector3::new(0, 1, 0)).unwrap(),
+                expected: None,
+            },
+        ];
+
+        for case in cases {
+            let result = ground.ray_intersect(&case.ray);
+
+            match (result, case.expected) {
+                (Some(t), Some(expected)) => assert!(t.approx_eq_default(&expected)),
+                (None, None) => {}
+                _ => panic!("{} expected {:?} got {:?}", case.ray, case.expected, result),
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use super::Plane;
+use std::fmt;
+
+impl fmt::Display for Plane {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} . p + {} = 0", self.normal, self.d)
+    }
+}
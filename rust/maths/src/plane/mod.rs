@@ -0,0 +1,17 @@
+mod approx_eq;
+mod core;
+mod display;
+
+use crate::vector3::Vector3;
+
+/// The set of points `p` where `normal · p + d = 0`, with a unit-length normal.
+#[derive(PartialEq, Debug)]
+pub struct Plane {
+    normal: Vector3,
+    d: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PlaneError {
+    CollinearPoints,
+}
@@ -0,0 +1,66 @@
+use crate::vector2::Vector2;
+
+/// Shoelace formula; positive for counter-clockwise winding. Fewer than three points
+/// enclose no area and give 0.
+pub fn polygon_signed_area(points: &[Vector2]) -> f64 {
+    if points.len() < 3 {
+        return 0.0;
+    }
+
+    let doubled: f64 = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.cross(b))
+        .sum();
+
+    doubled / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn signed_area() {
+        struct AreaCase {
+            points: Vec<Vector2>,
+            expected: f64,
+        }
+
+        let cases: Vec<AreaCase> = vec![
+            AreaCase {
+                points: vec![
+                    Vector2::new(0, 0),
+                    Vector2::new(1, 0),
+                    Vector2::new(1, 1),
+                    Vector2::new(0, 1),
+                ],
+                expected: 1.0,
+            },
+            AreaCase {
+                points: vec![
+                    Vector2::new(0, 0),
+                    Vector2::new(0, 1),
+                    Vector2::new(1, 1),
+                    Vector2::new(1, 0),
+                ],
+                expected: -1.0,
+            },
+            AreaCase {
+                points: vec![Vector2::new(1, 1), Vector2::new(5, 1), Vector2::new(3, 4)],
+                expected: 6.0,
+            },
+            AreaCase {
+                points: vec![Vector2::new(1, 1), Vector2::new(5, 1)],
+                expected: 0.0,
+            },
+        ];
+
+        for case in cases {
+            let result = polygon_signed_area(&case.points);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use super::Polynomial;
+use crate::complex::Complex;
+
+const ROOT_TOLERANCE: f64 = 1e-12;
+const REAL_ROOT_TOLERANCE: f64 = 1e-7;
+const DISCRIMINANT_TOLERANCE: f64 = 1e-12;
+const GCD_TOLERANCE: f64 = 1e-9;
+const MAX_ITERATIONS: usize = 500;
+
+impl Polynomial {
+    /// Ignores trailing zero coefficients; the zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.0.iter().rposition(|c| *c != 0.0).unwrap_or(0)
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        self.0.iter().rev().fold(0.0, |acc, c| acc * x + c)
+    }
+
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial(
+            self.0
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(power, c)| c * power as f64)
+                .collect(),
+        )
+    }
+
+    /// Returns the distinct real roots in ascending order, each once regardless of multiplicity.
+    /// Repeated factors are divided out first; degrees above 2 are then solved numerically with
+    /// Durand-Kerner, so those roots are only accurate to around 1e-7.
+    pub fn roots(&self) -> Vec<f64> {
+        if self.degree() == 0 {
+            return vec![];
+        }
+
+        let reduced = self.square_free();
+        let mut roots = match reduced.degree() {
+            0 => vec![],
+            1 => vec![-reduced.0[0] / reduced.0[1]],
+            2 => reduced.quadratic_roots(),
+            _ => reduced.durand_kerner_roots(),
+        };
+
+        roots.sort_by(|a, b| a.total_cmp(b));
+        roots
+    }
+
+    fn quadratic_roots(&self) -> Vec<f64> {
+        let (c, b, a) = (self.0[0], self.0[1], self.0[2]);
+        let discriminant = b * b - 4.0 * a * c;
+
+        // Cancellation in b² - 4ac leaves rounding noise on a double root, so compare to its terms
+        if discriminant.abs() <= DISCRIMINANT_TOLERANCE * (b * b).max((4.0 * a * c).abs()) {
+            return vec![-b / (2.0 * a)];
+        }
+        if discriminant < 0.0 {
+            return vec![];
+        }
+
+        let root = discriminant.sqrt();
+        vec![(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+    }
+
+    /// `p / gcd(p, p')`: the same roots, each with multiplicity one.
+    fn square_free(&self) -> Polynomial {
+        let coefficients = &self.0[..=self.degree()];
+        let divisor = gcd(coefficients, &self.derivative().0);
+        if divisor.len() <= 1 {
+            return Polynomial(coefficients.to_vec());
+        }
+
+        Polynomial(divide(coefficients, &divisor).0)
+    }
+
+    fn durand_kerner_roots(&self) -> Vec<f64> {
+        let degree = self.degree();
+        let leading = self.0[degree];
+        let monic: Vec<f64> = self.0[..=degree].iter().map(|c| c / leading).collect();
+
+        let seed = Complex::new(0.4, 0.9);
+        let mut roots: Vec<Complex> = vec![Complex::new(1, 0)];
+        for k in 1..degree {
+            roots.push(roots[k - 1].multiply(&seed));
+        }
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut largest_step: f64 = 0.0;
+
+            for i in 0..degree {
+                let mut denominator = Complex::new(1, 0);
+                for (j, other) in roots.iter().enumerate() {
+                    if i != j {
+                        denominator = denominator.multiply(&roots[i].subtract(other));
+                    }
+                }
+
+                let step = eval_complex(&monic, &roots[i]).divide(&denominator);
+                largest_step = largest_step.max(step.magnitude());
+                roots[i] = roots[i].subtract(&step);
+            }
+
+            if largest_step < ROOT_TOLERANCE {
+                break;
+            }
+        }
+
+        roots
+            .into_iter()
+            .filter(|z| z.im().abs() < REAL_ROOT_TOLERANCE)
+            .map(|z| z.re())
+            .collect()
+    }
+}
+
+/// Long division of coefficient slices, lowest power first, returning `(quotient, remainder)`.
+/// The divisor's leading coefficient must be non-zero.
+fn divide(numerator: &[f64], divisor: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let degree = divisor.len() - 1;
+    let mut remainder = numerator.to_vec();
+    if numerator.len() <= degree {
+        return (vec![0.0], remainder);
+    }
+
+    let mut quotient = vec![0.0; numerator.len() - degree];
+    for i in (0..quotient.len()).rev() {
+        let factor = remainder[i + degree] / divisor[degree];
+        quotient[i] = factor;
+        for (j, d) in divisor.iter().enumerate() {
+            remainder[i + j] -= factor * d;
+        }
+    }
+    remainder.truncate(degree);
+
+    (quotient, remainder)
+}
+
+/// Euclid's algorithm, rescaling each remainder so its largest coefficient is 1. Remainder
+/// coefficients below `GCD_TOLERANCE` of the dividend's scale count as zero. An empty result is
+/// the zero polynomial.
+fn gcd(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut a = normalized(a, max_magnitude(a));
+    let mut b = normalized(b, max_magnitude(b));
+    while !b.is_empty() {
+        let remainder = divide(&a, &b).1;
+        a = b;
+        b = normalized(&remainder, 1.0);
+    }
+
+    a
+}
+
+fn max_magnitude(coefficients: &[f64]) -> f64 {
+    coefficients.iter().fold(0.0, |m, c| m.max(c.abs()))
+}
+
+/// Drops leading coefficients that are negligible against `scale`, then divides by the largest.
+fn normalized(coefficients: &[f64], scale: f64) -> Vec<f64> {
+    let mut result = coefficients.to_vec();
+    while result
+        .last()
+        .is_some_and(|c| c.abs() <= GCD_TOLERANCE * scale)
+    {
+        result.pop();
+    }
+
+    let largest = max_magnitude(&result);
+    result.iter().map(|c| c / largest).collect()
+}
+
+fn eval_complex(coefficients: &[f64], z: &Complex) -> Complex {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Complex::new(0, 0), |acc, c| {
+            acc.multiply(z).add(&Complex::new(*c, 0))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn eval() {
+        let p = Polynomial(vec![1.0, -2.0, 0.0, 3.0]);
+
+        assert!(p.eval(0.0).approx_eq_default(&1.0));
+        assert!(p.eval(2.0).approx_eq_default(&21.0));
+        assert!(p.eval(-1.0).approx_eq_default(&0.0));
+    }
+
+    #[test]
+    fn derivative() {
+        let p = Polynomial(vec![1.0, -2.0, 0.0, 3.0]);
+
+        assert_eq!(p.derivative(), Polynomial(vec![-2.0, 0.0, 9.0]));
+        assert_eq!(Polynomial(vec![4.0]).derivative(), Polynomial(vec![]));
+    }
+
+    #[test]
+    fn degree_ignores_trailing_zeros() {
+        assert_eq!(Polynomial(vec![1.0, 2.0, 0.0, 0.0]).degree(), 1);
+        assert_eq!(Polynomial(vec![]).degree(), 0);
+    }
+
+    #[test]
+    fn roots() {
+        struct RootsCase {
+            polynomial: Polynomial,
+            expected: Vec<f64>,
+        }
+
+        let cases: Vec<RootsCase> = vec![
+            RootsCase {
+                polynomial: Polynomial(vec![6.0, -5.0, 1.0]),
+                expected: vec![2.0, 3.0],
+            },
+            RootsCase {
+                polynomial: Polynomial(vec![-4.0, 2.0]),
+                expected: vec![2.0],
+            },
+            RootsCase {
+                polynomial: Polynomial(vec![1.0, 0.0, 1.0]),
+                expected: vec![],
+            },
+            RootsCase {
+                polynomial: Polynomial(vec![-6.0, 11.0, -6.0, 1.0]),
+                expected: vec![1.0, 2.0, 3.0],
+            },
+            RootsCase {
+                polynomial: Polynomial(vec![-1.0, 0.0, 0.0, 1.0]),
+                expected: vec![1.0],
+            },
+            RootsCase {
+                polynomial: Polynomial(vec![0.0, 0.0, 1.0, 0.0]),
+                expected: vec![0.0],
+            },
+        ];
+
+        for case in cases {
+            let result = case.polynomial.roots();
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    fn from_roots(roots: &[f64]) -> Polynomial {
+        let mut coefficients = vec![1.0];
+        for root in roots {
+            let mut next = vec![0.0; coefficients.len() + 1];
+            for (power, c) in coefficients.iter().enumerate() {
+                next[power + 1] += c;
+                next[power] -= root * c;
+            }
+            coefficients = next;
+        }
+
+        Polynomial(coefficients)
+    }
+
+    #[test]
+    fn repeated_roots_are_reported_once() {
+        struct RepeatedCase {
+            roots: Vec<f64>,
+            expected: Vec<f64>,
+        }
+
+        let cases: Vec<RepeatedCase> = vec![
+            RepeatedCase {
+                roots: vec![0.3, 0.3],
+                expected: vec![0.3],
+            },
+            RepeatedCase {
+                roots: vec![0.1, 0.1],
+                expected: vec![0.1],
+            },
+            RepeatedCase {
+                roots: vec![1.0, 1.0, 1.0],
+                expected: vec![1.0],
+            },
+            RepeatedCase {
+                roots: vec![1.0, 1.0, 1.0, 1.0],
+                expected: vec![1.0],
+            },
+            RepeatedCase {
+                roots: vec![0.1, 0.1, 0.1, 0.1],
+                expected: vec![0.1],
+            },
+            RepeatedCase {
+                roots: vec![1.0, 1.0, 3.0],
+                expected: vec![1.0, 3.0],
+            },
+            RepeatedCase {
+                roots: vec![-2.0, -2.0, -2.0, 1.0, 1.0],
+                expected: vec![-2.0, 1.0],
+            },
+            RepeatedCase {
+                roots: vec![0.5, 2.0, 2.0, 2.5, -1.0],
+                expected: vec![-1.0, 0.5, 2.0, 2.5],
+            },
+        ];
+
+        for case in cases {
+            let result = from_roots(&case.roots).roots();
+
+            assert!(
+                result.approx_eq_default(&case.expected),
+                "roots {:?} gave {:?}",
+                case.roots,
+                result
+            );
+        }
+    }
+}
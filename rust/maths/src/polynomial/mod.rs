@@ -0,0 +1,5 @@
+mod core;
+
+/// Coefficients are stored lowest power first, so `[6, -5, 1]` is `x^2 - 5x + 6`.
+#[derive(PartialEq, Debug)]
+pub struct Polynomial(pub Vec<f64>);
@@ -0,0 +1,19 @@
+use super::Quaternion;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Quaternion {
+    fn approx_eq(&self, other: &Quaternion, eps: f64) -> bool {
+        self.w.approx_eq(&other.w, eps)
+            && self.x.approx_eq(&other.x, eps)
+            && self.y.approx_eq(&other.y, eps)
+            && self.z.approx_eq(&other.z, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.w
+            .abs()
+            .max(self.x.abs())
+            .max(self.y.abs())
+            .max(self.z.abs())
+    }
+}
@@ -0,0 +1,58 @@
+use super::Quaternion;
+use crate::matrix::Matrix;
+
+impl Quaternion {
+    /// Returns the 3x3 rotation matrix, assuming `self` is a unit quaternion.
+    pub fn to_matrix(&self) -> Matrix {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+
+        Matrix {
+            rows: 3,
+            columns: 3,
+            data: vec![
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approx_eq::ApproxEq;
+    use crate::matrix;
+    use crate::matrix::Matrix;
+    use crate::quaternion::Quaternion;
+    use crate::vector3::Vector3;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn identity_to_matrix() {
+        let result = Quaternion::identity().to_matrix();
+
+        assert!(result.approx_eq_default(&Matrix::identity(3)));
+    }
+
+    #[test]
+    fn rotation_about_z_to_matrix() {
+        let q = Quaternion::from_axis_angle(&Vector3::new(0, 0, 1), PI / 2.0);
+
+        let result = q.to_matrix();
+
+        let expected = matrix!(
+            rows: 3,
+            cols: 3,
+            0, -1, 0;
+            1, 0, 0;
+            0, 0, 1
+        );
+        assert!(result.approx_eq_default(&expected));
+    }
+}
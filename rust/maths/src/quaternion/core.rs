@@ -0,0 +1,253 @@
+use super::Quaternion;
+use crate::vector3::Vector3;
+
+const SLERP_LERP_THRESHOLD: f64 = 0.9995;
+
+impl Quaternion {
+    pub fn new<W: Into<f64>, X: Into<f64>, Y: Into<f64>, Z: Into<f64>>(
+        w: W,
+        x: X,
+        y: Y,
+        z: Z,
+    ) -> Quaternion {
+        Quaternion {
+            w: w.into(),
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+        }
+    }
+
+    pub fn w(&self) -> f64 {
+        self.w
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    pub fn identity() -> Quaternion {
+        Quaternion::new(1, 0, 0, 0)
+    }
+
+    pub fn from_axis_angle(axis: &Vector3, radians: f64) -> Quaternion {
+        let axis = axis.normalize();
+        let (sin, cos) = (radians / 2.0).sin_cos();
+
+        Quaternion {
+            w: cos,
+            x: axis.x() * sin,
+            y: axis.y() * sin,
+            z: axis.z() * sin,
+        }
+    }
+
+    /// Hamilton product, the result applies `other` first and then `self`.
+    pub fn multiply(&self, other: &Quaternion) -> Quaternion {
+        Quaternion {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        }
+    }
+
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    pub fn dot_product(&self, other: &Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.dot_product(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quaternion {
+        let mag = self.magnitude();
+        Quaternion::new(self.w / mag, self.x / mag, self.y / mag, self.z / mag)
+    }
+
+    pub fn rotate_vector(&self, v: &Vector3) -> Vector3 {
+        let p = Quaternion::new(0, v.x(), v.y(), v.z());
+        let rotated = self.multiply(&p).multiply(&self.conjugate());
+
+        Vector3::new(rotated.x, rotated.y, rotated.z)
+    }
+
+    /// Interpolates along the shortest arc, falling back to a normalized lerp when the
+    /// rotations are almost parallel.
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let mut cos_theta = self.dot_product(other);
+        let sign = if cos_theta < 0.0 { -1.0 } else { 1.0 };
+        cos_theta *= sign;
+
+        if cos_theta > SLERP_LERP_THRESHOLD {
+            return self.weighted_sum(1.0 - t, other, sign * t).normalize();
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let a = ((1.0 - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+
+        self.weighted_sum(a, other, sign * b)
+    }
+
+    fn weighted_sum(&self, a: f64, other: &Quaternion, b: f64) -> Quaternion {
+        Quaternion {
+            w: self.w * a + other.w * b,
+            x: self.x * a + other.x * b,
+            y: self.y * a + other.y * b,
+            z: self.z * a + other.z * b,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn identity_leaves_vector_unchanged() {
+        let v = Vector3::new(1, -2, 3);
+
+        let result = Quaternion::identity().rotate_vector(&v);
+
+        assert!(result.approx_eq_default(&v));
+    }
+
+    #[test]
+    fn rotate_vector() {
+        struct RotateCase {
+            axis: Vector3,
+            radians: f64,
+            input: Vector3,
+            expected: Vector3,
+        }
+
+        let cases: Vec<RotateCase> = vec![
+            RotateCase {
+                axis: Vector3::new(0, 0, 1),
+                radians: PI / 2.0,
+                input: Vector3::new(1, 0, 0),
+                expected: Vector3::new(0, 1, 0),
+            },
+            RotateCase {
+                axis: Vector3::new(0, 3, 0),
+                radians: PI,
+                input: Vector3::new(1, 2, 3),
+                expected: Vector3::new(-1, 2, -3),
+            },
+            RotateCase {
+                axis: Vector3::new(1, 1, 1),
+                radians: 2.0 * PI / 3.0,
+                input: Vector3::new(1, 0, 0),
+                expected: Vector3::new(0, 1, 0),
+            },
+        ];
+
+        for case in cases {
+            let q = Quaternion::from_axis_angle(&case.axis, case.radians);
+
+            let result = q.rotate_vector(&case.input);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn composition_matches_sequential_rotation() {
+        let first = Quaternion::from_axis_angle(&Vector3::new(0, 0, 1), PI / 3.0);
+        let second = Quaternion::from_axis_angle(&Vector3::new(1, -2, 0.5), 1.2);
+        let v = Vector3::new(0.3, 4, -1.5);
+
+        let composed = second.multiply(&first).rotate_vector(&v);
+        let sequential = second.rotate_vector(&first.rotate_vector(&v));
+
+        assert!(composed.approx_eq_default(&sequential));
+    }
+
+    #[test]
+    fn conjugate_inverts_unit_rotation() {
+        let q = Quaternion::from_axis_angle(&Vector3::new(2, 1, -1), 0.7);
+
+        let result = q.multiply(&q.conjugate());
+
+        assert!(result.approx_eq_default(&Quaternion::identity()));
+    }
+
+    #[test]
+    fn normalize() {
+        let q = Quaternion::new(1, 2, 2, 4);
+
+        let result = q.normalize();
+
+        assert!(result.approx_eq_default(&Quaternion::new(0.2, 0.4, 0.4, 0.8)));
+        assert!(result.magnitude().approx_eq_default(&1.0));
+    }
+
+    #[test]
+    fn slerp_endpoints() {
+        let a = Quaternion::from_axis_angle(&Vector3::new(0, 0, 1), 0.3);
+        let b = Quaternion::from_axis_angle(&Vector3::new(1, 2, 0), 2.1);
+
+        assert!(a.slerp(&b, 0.0).approx_eq_default(&a));
+        assert!(a.slerp(&b, 1.0).approx_eq_default(&b));
+    }
+
+    #[test]
+    fn slerp_midpoint() {
+        let axis = Vector3::new(0, 1, 0);
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(&axis, PI / 2.0);
+
+        let result = a.slerp(&b, 0.5);
+
+        assert!(result.magnitude().approx_eq_default(&1.0));
+        assert!(result.approx_eq_default(&Quaternion::from_axis_angle(&axis, PI / 4.0)));
+    }
+
+    #[test]
+    fn slerp_takes_shortest_arc() {
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(&Vector3::new(0, 0, 1), PI / 2.0);
+        let negated_b = Quaternion::new(-b.w(), -b.x(), -b.y(), -b.z());
+        let v = Vector3::new(1, 0, 0);
+
+        let result = a.slerp(&negated_b, 0.5).rotate_vector(&v);
+
+        let expected = Vector3::new((PI / 4.0).cos(), (PI / 4.0).sin(), 0);
+        assert!(result.approx_eq_default(&expected));
+    }
+
+    #[test]
+    fn slerp_nearly_parallel() {
+        let a = Quaternion::from_axis_angle(&Vector3::new(1, 0, 0), 0.5);
+        let b = Quaternion::from_axis_angle(&Vector3::new(1, 0, 0), 0.5001);
+
+        let result = a.slerp(&b, 0.5);
+
+        assert!(result.magnitude().approx_eq_default(&1.0));
+        assert!(result.approx_eq_default(&Quaternion::from_axis_angle(
+            &Vector3::new(1, 0, 0),
+            0.50005
+        )));
+    }
+}
@@ -0,0 +1,8 @@
+use super::Quaternion;
+use std::fmt;
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.w, self.x, self.y, self.z)
+    }
+}
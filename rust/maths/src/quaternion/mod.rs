@@ -0,0 +1,12 @@
+mod approx_eq;
+mod conversion;
+mod core;
+mod display;
+
+#[derive(PartialEq, Debug)]
+pub struct Quaternion {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
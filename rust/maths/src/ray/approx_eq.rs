@@ -0,0 +1,12 @@
+use super::Ray;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Ray {
+    fn approx_eq(&self, other: &Ray, eps: f64) -> bool {
+        self.origin.approx_eq(&other.origin, eps) && self.direction.approx_eq(&other.direction, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.origin.max_abs().max(self.direction.max_abs())
+    }
+}
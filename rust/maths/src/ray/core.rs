@@ -0,0 +1,80 @@
+use super::{Ray, RayError};
+use crate::vector3::Vector3;
+
+impl Ray {
+    /// The direction is normalized, so `t` measures distance along the ray. A zero direction
+    /// has nothing to normalize and is rejected.
+    pub fn new(origin: Vector3, direction: Vector3) -> Result<Ray, RayError> {
+        if direction.magnitude() == 0.0 {
+            return Err(RayError::ZeroDirection);
+        }
+
+        Ok(Ray {
+            origin,
+            direction: direction.normalize(),
+        })
+    }
+
+    pub fn origin(&self) -> &Vector3 {
+        &self.origin
+    }
+
+    pub fn direction(&self) -> &Vector3 {
+        &self.direction
+    }
+
+    pub fn point_at(&self, t: f64) -> Vector3 {
+        self.origin.add(&self.direction.multiply(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn new_normalizes_direction() {
+        let ray = Ray::new(Vector3::new(1, 2, 3), Vector3::new(0, 0, 5)).unwrap();
+
+        assert!(ray.direction().approx_eq_default(&Vector3::new(0, 0, 1)));
+        assert!(ray.origin().approx_eq_default(&Vector3::new(1, 2, 3)));
+    }
+
+    #[test]
+    fn new_rejects_zero_direction() {
+        let result = Ray::new(Vector3::new(1, 2, 3), Vector3::zero());
+
+        assert_eq!(result, Err(RayError::ZeroDirection));
+    }
+
+    #[test]
+    fn point_at() {
+        struct PointCase {
+            t: f64,
+            expected: Vector3,
+        }
+
+        let ray = Ray::new(Vector3::new(1, 2, 3), Vector3::new(3, 0, 4)).unwrap();
+        let cases: Vec<PointCase> = vec![
+            PointCase {
+                t: 0.0,
+                expected: Vector3::new(1, 2, 3),
+            },
+            PointCase {
+                t: 2.0,
+                expected: Vector3::new(2.2, 2, 4.6),
+            },
+            PointCase {
+                t: -5.0,
+                expected: Vector3::new(-2, 2, -1),
+            },
+        ];
+
+        for case in cases {
+            let result = ray.point_at(case.t);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use super::Ray;
+use std::fmt;
+
+impl fmt::Display for Ray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + t{}", self.origin, self.direction)
+    }
+}
@@ -0,0 +1,16 @@
+mod approx_eq;
+mod core;
+mod display;
+
+use crate::vector3::Vector3;
+
+#[derive(PartialEq, Debug)]
+pub struct Ray {
+    origin: Vector3,
+    direction: Vector3,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RayError {
+    ZeroDirection,
+}
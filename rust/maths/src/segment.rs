@@ -0,0 +1,94 @@
+use crate::vector2::Vector2;
+
+const PARALLEL_EPSILON: f64 = 1e-12;
+
+/// Parallel and collinear segments return `None`, as they have no single crossing point.
+pub fn segment_intersect(
+    a0: &Vector2,
+    a1: &Vector2,
+    b0: &Vector2,
+    b1: &Vector2,
+) -> Option<Vector2> {
+    let r = a1.subtract(a0);
+    let s = b1.subtract(b0);
+
+    let denominator = r.cross(&s);
+    if denominator.abs() < PARALLEL_EPSILON {
+        return None;
+    }
+
+    let offset = b0.subtract(a0);
+    let t = offset.cross(&s) / denominator;
+    let u = offset.cross(&r) / denominator;
+
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some(a0.add(&r.multiply(t)))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn segment_intersections() {
+        struct SegmentCase {
+            a0: Vector2,
+            a1: Vector2,
+            b0: Vector2,
+            b1: Vector2,
+            expected: Option<Vector2>,
+        }
+
+        let cases: Vec<SegmentCase> = vec![
+            SegmentCase {
+                a0: Vector2::new(0, 0),
+                a1: Vector2::new(4, 4),
+                b0: Vector2::new(0, 4),
+                b1: Vector2::new(4, 0),
+                expected: Some(Vector2::new(2, 2)),
+            },
+            SegmentCase {
+                a0: Vector2::new(0, 0),
+                a1: Vector2::new(4, 0),
+                b0: Vector2::new(0, 1),
+                b1: Vector2::new(4, 1),
+                expected: None,
+            },
+            SegmentCase {
+                a0: Vector2::new(0, 0),
+                a1: Vector2::new(4, 0),
+                b0: Vector2::new(2, 0),
+                b1: Vector2::new(6, 0),
+                expected: None,
+            },
+            SegmentCase {
+                a0: Vector2::new(0, 0),
+                a1: Vector2::new(4, 0),
+                b0: Vector2::new(2, 0),
+                b1: Vector2::new(2, 3),
+                expected: Some(Vector2::new(2, 0)),
+            },
+            SegmentCase {
+                a0: Vector2::new(0, 0),
+                a1: Vector2::new(1, 1),
+                b0: Vector2::new(3, 0),
+                b1: Vector2::new(0, 3),
+                expected: None,
+            },
+        ];
+
+        for case in cases {
+            let result = segment_intersect(&case.a0, &case.a1, &case.b0, &case.b1);
+
+            match (&result, &case.expected) {
+                (Some(point), Some(expected)) => assert!(point.approx_eq_default(expected)),
+                (None, None) => {}
+                _ => panic!("expected {:?} got {:?}", case.expected, result),
+            }
+        }
+    }
+}
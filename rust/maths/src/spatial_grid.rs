@@ -0,0 +1,124 @@
+use crate::vector2::Vector2;
+use std::collections::HashMap;
+
+/// Buckets points into square cells so radius queries only visit nearby cells.
+pub struct SpatialGrid<Id> {
+    cell_size: f64,
+    cells: HashMap<(i64, i64), Vec<(Id, Vector2)>>,
+}
+
+impl<Id: Copy> SpatialGrid<Id> {
+    /// `cell_size` must be positive; around the typical query radius works best.
+    pub fn new(cell_size: f64) -> SpatialGrid<Id> {
+        SpatialGrid {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell_of(&self, x: f64, y: f64) -> (i64, i64) {
+        (
+            (x / self.cell_size).floor() as i64,
+            (y / self.cell_size).floor() as i64,
+        )
+    }
+
+    pub fn insert(&mut self, id: Id, position: &Vector2) {
+        let cell = self.cell_of(position.x(), position.y());
+
+        self.cells
+            .entry(cell)
+            .or_default()
+            .push((id, Vector2::new(position.x(), position.y())));
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Ids of every point within `radius` of `center`, boundary included, in no particular order.
+    pub fn query_radius(&self, center: &Vector2, radius: f64) -> Vec<Id> {
+        let (min_x, min_y) = self.cell_of(center.x() - radius, center.y() - radius);
+        let (max_x, max_y) = self.cell_of(center.x() + radius, center.y() + radius);
+        let radius_squared = radius * radius;
+
+        let mut result = Vec::new();
+        for cell_x in min_x..=max_x {
+            for cell_y in min_y..=max_y {
+                let Some(bucket) = self.cells.get(&(cell_x, cell_y)) else {
+                    continue;
+                };
+
+                result.extend(
+                    bucket
+                        .iter()
+                        .filter(|(_, p)| p.distance_squared(center) <= radius_squared)
+                        .map(|(id, _)| *id),
+                );
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_radius() {
+        let mut grid = SpatialGrid::new(2.0);
+        let points = [
+            (1, Vector2::new(0, 0)),
+            (2, Vector2::new(2.9, 0)),
+            (3, Vector2::new(3.1, 0)),
+            (4, Vector2::new(-2, -2)),
+            (5, Vector2::new(0, -3)),
+            (6, Vector2::new(50, 50)),
+        ];
+        for (id, p) in points.iter() {
+            grid.insert(*id, p);
+        }
+
+        let mut result = grid.query_radius(&Vector2::new(0, 0), 3.0);
+        result.sort();
+
+        assert_eq!(result, vec![1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn query_matches_brute_force() {
+        let mut grid = SpatialGrid::new(1.5);
+        let points: Vec<Vector2> = (0..100)
+            .map(|i| {
+                let i = i as f64;
+                Vector2::new((i * 7.3) % 20.0 - 10.0, (i * 3.7) % 20.0 - 10.0)
+            })
+            .collect();
+        for (id, p) in points.iter().enumerate() {
+            grid.insert(id, p);
+        }
+
+        let center = Vector2::new(1.5, -2.0);
+        let mut result = grid.query_radius(&center, 4.0);
+        result.sort();
+        let expected: Vec<usize> = points
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.distance_squared(&center) <= 16.0)
+            .map(|(id, _)| id)
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn clear() {
+        let mut grid = SpatialGrid::new(1.0);
+        grid.insert("a", &Vector2::new(0.5, 0.5));
+        grid.clear();
+
+        assert!(grid.query_radius(&Vector2::new(0.5, 0.5), 1.0).is_empty());
+    }
+}
@@ -0,0 +1,12 @@
+use super::Sphere;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Sphere {
+    fn approx_eq(&self, other: &Sphere, eps: f64) -> bool {
+        self.center.approx_eq(&other.center, eps) && self.radius.approx_eq(&other.radius, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.center.max_abs().max(self.radius.abs())
+    }
+}
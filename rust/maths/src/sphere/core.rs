@@ -0,0 +1,84 @@
+use super::Sphere;
+use crate::ray::Ray;
+use crate::vector3::Vector3;
+
+impl Sphere {
+    pub fn new(center: Vector3, radius: f64) -> Sphere {
+        Sphere { center, radius }
+    }
+
+    pub fn center(&self) -> &Vector3 {
+        &self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Returns the nearest `t` in front of the ray's origin, which is the exit point when
+    /// the origin is inside the sphere.
+    pub fn ray_intersect(&self, ray: &Ray) -> Option<f64> {
+        let oc = ray.origin().subtract(&self.center);
+        let b = oc.dot_product(ray.direction());
+        let c = oc.magnitude_squared() - self.radius * self.radius;
+
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        [-b - root, -b + root].into_iter().find(|t| *t >= 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    fn unit_sphere() -> Sphere {
+        Sphere::new(Vector3::new(0, 0, 0), 1.0)
+    }
+
+    #[test]
+    fn ray_intersect() {
+        struct IntersectCase {
+            ray: Ray,
+            expected: Option<f64>,
+        }
+
+        let cases: Vec<IntersectCase> = vec![
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, 0, -5), Vector3::new(0, 0, 1)).unwrap(),
+                expected: Some(4.0),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, 1, -5), Vector3::new(0, 0, 1)).unwrap(),
+                expected: Some(5.0),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, 0, 0), Vector3::new(1, 0, 0)).unwrap(),
+                expected: Some(1.0),
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, 2, -5), Vector3::new(0, 0, 1)).unwrap(),
+                expected: None,
+            },
+            IntersectCase {
+                ray: Ray::new(Vector3::new(0, 0, 5), Vector3::new(0, 0, 1)).unwrap(),
+                expected: None,
+            },
+        ];
+
+        for case in cases {
+            let result = unit_sphere().ray_intersect(&case.ray);
+
+            match (result, case.expected) {
+                (Some(t), Some(expected)) => assert!(t.approx_eq_default(&expected)),
+                (None, None) => {}
+                _ => panic!("{} expected {:?} got {:?}", case.ray, case.expected, result),
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use super::Sphere;
+use std::fmt;
+
+impl fmt::Display for Sphere {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} r{}", self.center, self.radius)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::vector3::Vector3;
+
+/// Uniform Catmull-Rom segment between `p1` and `p2`, with `p0` and `p3` shaping the tangents.
+pub fn catmull_rom(p0: &Vector3, p1: &Vector3, p2: &Vector3, p3: &Vector3, t: f64) -> Vector3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let linear = p2.subtract(p0).multiply(t);
+    let quadratic = p0
+        .multiply(2.0)
+        .subtract(&p1.multiply(5.0))
+        .add(&p2.multiply(4.0))
+        .subtract(p3)
+        .multiply(t2);
+    let cubic = p1
+        .subtract(p2)
+        .multiply(3.0)
+        .add(p3)
+        .subtract(p0)
+        .multiply(t3);
+
+    p1.multiply(2.0)
+        .add(&linear)
+        .add(&quadratic)
+        .add(&cubic)
+        .multiply(0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    fn waypoints() -> Vec<Vector3> {
+        vec![
+            Vector3::new(0, 0, 0),
+            Vector3::new(1, 2, 0),
+            Vector3::new(3, 3, 1),
+            Vector3::new(4, 1, 2),
+            Vector3::new(6, 0, 2),
+        ]
+    }
+
+    #[test]
+    fn passes_through_inner_points() {
+        let p = waypoints();
+
+        assert!(catmull_rom(&p[0], &p[1], &p[2], &p[3], 0.0).approx_eq_default(&p[1]));
+        assert!(catmull_rom(&p[0], &p[1], &p[2], &p[3], 1.0).approx_eq_default(&p[2]));
+    }
+
+    #[test]
+    fn continuous_tangent_across_segments() {
+        let p = waypoints();
+        let h = 1e-6;
+
+        let end = catmull_rom(&p[0], &p[1], &p[2], &p[3], 1.0);
+        let before_end = catmull_rom(&p[0], &p[1], &p[2], &p[3], 1.0 - h);
+        let start = catmull_rom(&p[1], &p[2], &p[3], &p[4], 0.0);
+        let after_start = catmull_rom(&p[1], &p[2], &p[3], &p[4], h);
+
+        let incoming = end.subtract(&before_end).divide(h);
+        let outgoing = after_start.subtract(&start).divide(h);
+
+        assert!(end.approx_eq_default(&start));
+        assert!(incoming.approx_eq(&outgoing, 1e-4));
+        assert!(incoming.approx_eq(&p[3].subtract(&p[1]).multiply(0.5), 1e-4));
+    }
+}
@@ -0,0 +1,17 @@
+use super::Transform;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for Transform {
+    fn approx_eq(&self, other: &Transform, eps: f64) -> bool {
+        self.translation.approx_eq(&other.translation, eps)
+            && self.rotation.approx_eq(&other.rotation, eps)
+            && self.scale.approx_eq(&other.scale, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.translation
+            .max_abs()
+            .max(self.rotation.max_abs())
+            .max(self.scale.max_abs())
+    }
+}
@@ -0,0 +1,132 @@
+use super::Transform;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::vector3::Vector3;
+
+impl Transform {
+    pub fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Transform {
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn identity() -> Transform {
+        Transform::new(Vector3::zero(), Quaternion::identity(), Vector3::one())
+    }
+
+    pub fn translation(&self) -> &Vector3 {
+        &self.translation
+    }
+
+    pub fn rotation(&self) -> &Quaternion {
+        &self.rotation
+    }
+
+    pub fn scale(&self) -> &Vector3 {
+        &self.scale
+    }
+
+    /// Returns the 4x4 homogeneous TRS matrix, assuming the rotation is a unit quaternion.
+    pub fn to_matrix(&self) -> Matrix {
+        let rotation = self.rotation.to_matrix();
+        let scale = [self.scale.x(), self.scale.y(), self.scale.z()];
+        let translation = [
+            self.translation.x(),
+            self.translation.y(),
+            self.translation.z(),
+        ];
+
+        let mut result = Matrix::identity(4);
+        for (row, offset) in translation.into_iter().enumerate() {
+            for (column, factor) in scale.iter().enumerate() {
+                result.data[row * 4 + column] = rotation.data[row * 3 + column] * factor;
+            }
+            result.data[row * 4 + 3] = offset;
+        }
+
+        result
+    }
+
+    pub fn transform_point(&self, point: &Vector3) -> Vector3 {
+        self.transform_direction(point).add(&self.translation)
+    }
+
+    /// Applies scale and rotation only, ignoring translation.
+    pub fn transform_direction(&self, direction: &Vector3) -> Vector3 {
+        self.rotation
+            .rotate_vector(&direction.component_multiply(&self.scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::col_vector;
+    use std::f64::consts::PI;
+
+    fn sample() -> Transform {
+        Transform::new(
+            Vector3::new(1, 2, 3),
+            Quaternion::from_axis_angle(&Vector3::unit_z(), PI / 2.0),
+            Vector3::new(2, 2, 2),
+        )
+    }
+
+    #[test]
+    fn identity() {
+        let point = Vector3::new(3, -1, 2);
+
+        assert!(
+            Transform::identity()
+                .to_matrix()
+                .approx_eq_default(&Matrix::identity(4))
+        );
+        assert!(
+            Transform::identity()
+                .transform_point(&point)
+                .approx_eq_default(&point)
+        );
+    }
+
+    #[test]
+    fn transform_point() {
+        let result = sample().transform_point(&Vector3::new(1, 0, 0));
+
+        assert!(result.approx_eq_default(&Vector3::new(1, 4, 3)));
+    }
+
+    #[test]
+    fn transform_direction_ignores_translation() {
+        let result = sample().transform_direction(&Vector3::new(1, 0, 0));
+
+        assert!(result.approx_eq_default(&Vector3::new(0, 2, 0)));
+    }
+
+    #[test]
+    fn matrix_matches_transform_point() {
+        let transform = sample();
+        let points = vec![
+            Vector3::new(1, 0, 0),
+            Vector3::new(0, 1, 0),
+            Vector3::new(-2, 3.5, 1),
+        ];
+
+        for point in points {
+            let expected = transform.transform_point(&point);
+            let result = transform
+                .to_matrix()
+                .multiply(&col_vector!(point.x(), point.y(), point.z(), 1))
+                .unwrap();
+
+            assert!(result.approx_eq_default(&col_vector!(
+                expected.x(),
+                expected.y(),
+                expected.z(),
+                1
+            )));
+        }
+    }
+}
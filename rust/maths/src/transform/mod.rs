@@ -0,0 +1,13 @@
+mod approx_eq;
+mod core;
+
+use crate::quaternion::Quaternion;
+use crate::vector3::Vector3;
+
+/// Applies scale, then rotation, then translation.
+#[derive(PartialEq, Debug)]
+pub struct Transform {
+    translation: Vector3,
+    rotation: Quaternion,
+    scale: Vector3,
+}
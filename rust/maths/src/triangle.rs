@@ -0,0 +1,174 @@
+use crate::ray::Ray;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+const PARALLEL_EPSILON: f64 = 1e-12;
+
+pub fn triangle_area(a: &Vector3, b: &Vector3, c: &Vector3) -> f64 {
+    b.subtract(a).cross_product(&c.subtract(a)).magnitude() / 2.0
+}
+
+/// Returns the weights `(u, v, w)` of `a`, `b` and `c` such that `p = ua + vb + wc`. All three
+/// lie in `[0, 1]` exactly when `p` is inside the triangle; a degenerate triangle gives NaN.
+pub fn barycentric(p: &Vector2, a: &Vector2, b: &Vector2, c: &Vector2) -> (f64, f64, f64) {
+    let ab = b.subtract(a);
+    let ac = c.subtract(a);
+    let ap = p.subtract(a);
+
+    let area = ab.cross(&ac);
+    let v = ap.cross(&ac) / area;
+    let w = ab.cross(&ap) / area;
+
+    (1.0 - v - w, v, w)
+}
+
+/// Moller-Trumbore. Front faces wind counter-clockwise as seen from the ray's origin; with
+/// `cull_back_faces` set, hits on the other side are ignored.
+pub fn ray_triangle_intersect(
+    ray: &Ray,
+    v0: &Vector3,
+    v1: &Vector3,
+    v2: &Vector3,
+    cull_back_faces: bool,
+) -> Option<f64> {
+    let edge1 = v1.subtract(v0);
+    let edge2 = v2.subtract(v0);
+    let p = ray.direction().cross_product(&edge2);
+    let determinant = edge1.dot_product(&p);
+
+    if determinant.abs() < PARALLEL_EPSILON || (cull_back_faces && determinant < 0.0) {
+        return None;
+    }
+
+    let inverse = 1.0 / determinant;
+    let s = ray.origin().subtract(v0);
+    let u = s.dot_product(&p) * inverse;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross_product(&edge1);
+    let v = ray.direction().dot_product(&q) * inverse;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = edge2.dot_product(&q) * inverse;
+    if t < 0.0 {
+        return None;
+    }
+
+    Some(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn area() {
+        let unit = triangle_area(
+            &Vector3::new(0, 0, 0),
+            &Vector3::new(1, 0, 0),
+            &Vector3::new(0, 1, 0),
+        );
+        let tilted = triangle_area(
+            &Vector3::new(1, 1, 1),
+            &Vector3::new(1, 4, 1),
+            &Vector3::new(1, 1, 5),
+        );
+
+        assert!(unit.approx_eq_default(&0.5));
+        assert!(tilted.approx_eq_default(&6.0));
+    }
+
+    #[test]
+    fn barycentric_weights() {
+        let a = Vector2::new(0, 0);
+        let b = Vector2::new(3, 0);
+        let c = Vector2::new(0, 3);
+
+        struct BarycentricCase {
+            p: Vector2,
+            expected: (f64, f64, f64),
+        }
+
+        let cases: Vec<BarycentricCase> = vec![
+            BarycentricCase {
+                p: Vector2::new(1, 1),
+                expected: (1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0),
+            },
+            BarycentricCase {
+                p: Vector2::new(3, 0),
+                expected: (0.0, 1.0, 0.0),
+            },
+            BarycentricCase {
+                p: Vector2::new(3, 3),
+                expected: (-1.0, 1.0, 1.0),
+            },
+        ];
+
+        for case in cases {
+            let result = barycentric(&case.p, &a, &b, &c);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn ray_triangle() {
+        let v0 = Vector3::new(0, 0, 0);
+        let v1 = Vector3::new(2, 0, 0);
+        let v2 = Vector3::new(0, 2, 0);
+
+        struct RayTriangleCase {
+            ray: Ray,
+            cull_back_faces: bool,
+            expected: Option<f64>,
+        }
+
+        let cases: Vec<RayTriangleCase> = vec![
+            RayTriangleCase {
+                ray: Ray::new(Vector3::new(0.5, 0.5, 5), Vector3::new(0, 0, -1)).unwrap(),
+                cull_back_faces: true,
+                expected: Some(5.0),
+            },
+            RayTriangleCase {
+                ray: Ray::new(Vector3::new(1.5, 1.5, 5), Vector3::new(0, 0, -1)).unwrap(),
+                cull_back_faces: false,
+                expected: None,
+            },
+            RayTriangleCase {
+                ray: Ray::new(Vector3::new(-1, 0.5, 0), Vector3::new(1, 0, 0)).unwrap(),
+                cull_back_faces: false,
+                expected: None,
+            },
+            RayTriangleCase {
+                ray: Ray::new(Vector3::new(0.5, 0.5, -3), Vector3::new(0, 0, 1)).unwrap(),
+                cull_back_faces: false,
+                expected: Some(3.0),
+            },
+            RayTriangleCase {
+                ray: Ray::new(Vector3::new(0.5, 0.5, -3), Vector3::new(0, 0, 1)).unwrap(),
+                cull_back_faces: true,
+                expected: None,
+            },
+            RayTriangleCase {
+                ray: Ray::new(Vector3::new(0.5, 0.5, 5), Vector3::new(0, 0, 1)).unwrap(),
+                cull_back_faces: false,
+                expected: None,
+            },
+        ];
+
+        for case in cases {
+            let result = ray_triangle_intersect(&case.ray, &v0, &v1, &v2, case.cull_back_faces);
+
+            match (result, case.expected) {
+                (Some(t), Some(expected)) => assert!(t.approx_eq_default(&expected)),
+                (None, None) => {}
+                _ => panic!("{} expected {:?} got {:?}", case.ray, case.expected, result),
+            }
+        }
+    }
+}
@@ -5,4 +5,8 @@ impl ApproxEq for Vector2 {
     fn approx_eq(&self, other: &Vector2, eps: f64) -> bool {
         self.x.approx_eq(&other.x, eps) && self.y.approx_eq(&other.y, eps)
     }
+
+    fn max_abs(&self) -> f64 {
+        self.x.abs().max(self.y.abs())
+    }
 }
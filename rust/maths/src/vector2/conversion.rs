@@ -0,0 +1,89 @@
+use super::Vector2;
+use crate::vector3::Vector3;
+
+impl From<(f64, f64)> for Vector2 {
+    fn from((x, y): (f64, f64)) -> Self {
+        Vector2 { x, y }
+    }
+}
+
+impl From<[f64; 2]> for Vector2 {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Vector2 { x, y }
+    }
+}
+
+impl Vector2 {
+    /// `angle` is in radians, counter-clockwise from +x.
+    pub fn from_polar(radius: f64, angle: f64) -> Vector2 {
+        Vector2::new(radius * angle.cos(), radius * angle.sin())
+    }
+
+    /// Returns `(radius, angle)` with the angle in `(-PI, PI]`; the zero vector has angle 0.
+    pub fn to_polar(&self) -> (f64, f64) {
+        if self.x == 0.0 && self.y == 0.0 {
+            return (0.0, 0.0);
+        }
+
+        (self.magnitude(), self.y.atan2(self.x))
+    }
+
+    pub fn to_array(&self) -> [f64; 2] {
+        [self.x, self.y]
+    }
+
+    pub fn to_vector3(&self, z: f64) -> Vector3 {
+        Vector3::new(self.x, self.y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approx_eq::ApproxEq;
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn from_tuple() {
+        let result = Vector2::from((3.5, -2.0));
+
+        assert!(result.approx_eq_default(&Vector2::new(3.5, -2.0)));
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let cases: Vec<Vector2> = vec![
+            Vector2::new(3, 4),
+            Vector2::new(-2.5, 15),
+            Vector2::new(0, 0),
+        ];
+
+        for case in cases {
+            let result = Vector2::from(case.to_array());
+
+            assert_eq!(result, case);
+        }
+    }
+
+    #[test]
+    fn to_vector3() {
+        let result = Vector2::new(1, 2).to_vector3(3.0);
+
+        assert!(result.approx_eq_default(&Vector3::new(1, 2, 3)));
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        let (radius, angle) = Vector2::new(1, 1).to_polar();
+
+        assert!(radius.approx_eq_default(&2f64.sqrt()));
+        assert!(angle.approx_eq_default(&(PI / 4.0)));
+        assert!(Vector2::from_polar(radius, angle).approx_eq_default(&Vector2::new(1, 1)));
+    }
+
+    #[test]
+    fn polar_of_zero() {
+        assert_eq!(Vector2::zero().to_polar(), (0.0, 0.0));
+    }
+}
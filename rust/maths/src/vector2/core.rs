@@ -7,6 +7,31 @@ impl Vector2 {
             y: y.into(),
         }
     }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn zero() -> Vector2 {
+        Vector2::new(0, 0)
+    }
+
+    pub fn one() -> Vector2 {
+        Vector2::new(1, 1)
+    }
+
+    pub fn unit_x() -> Vector2 {
+        Vector2::new(1, 0)
+    }
+
+    pub fn unit_y() -> Vector2 {
+        Vector2::new(0, 1)
+    }
+
     pub fn add(&self, other: &Vector2) -> Vector2 {
         Vector2 {
             x: self.x + other.x,
@@ -28,29 +53,102 @@ impl Vector2 {
         }
     }
 
+    pub fn component_multiply(&self, other: &Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+
+    /// Follows float semantics, so dividing by zero yields infinite or NaN components.
+    pub fn divide(&self, divisor: f64) -> Vector2 {
+        Vector2 {
+            x: self.x / divisor,
+            y: self.y / divisor,
+        }
+    }
+
     pub fn dot_product(&self, other: &Vector2) -> f64 {
         self.x * other.x + self.y * other.y
     }
 
+    pub fn cross(&self, other: &Vector2) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn magnitude_squared(&self) -> f64 {
+        self.x * self.x + self.y * self.y
+    }
+
     pub fn magnitude(&self) -> f64 {
-        let x_sq = self.x.powf(2.0);
-        let y_sq = self.y.powf(2.0);
-        let sum_sq = x_sq + y_sq;
-        sum_sq.sqrt()
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn distance(&self, other: &Vector2) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+
+    pub fn distance_squared(&self, other: &Vector2) -> f64 {
+        self.subtract(other).magnitude_squared()
+    }
+
+    pub fn clamp_magnitude(&self, max: f64) -> Vector2 {
+        let mag_sq = self.magnitude_squared();
+        if mag_sq <= max * max {
+            return Vector2::new(self.x, self.y);
+        }
+
+        self.multiply(max / mag_sq.sqrt())
     }
 
     pub fn angle_between(&self, other: &Vector2) -> f64 {
         let dot = self.dot_product(other);
         let mags = self.magnitude() * other.magnitude();
 
-        let cos_theta = dot / mags;
+        let cos_theta = (dot / mags).clamp(-1.0, 1.0);
         cos_theta.acos()
     }
 
+    pub fn angle_between_degrees(&self, other: &Vector2) -> f64 {
+        self.angle_between(other).to_degrees()
+    }
+
+    /// Positive when `other` is counter-clockwise from `self`.
+    pub fn signed_angle(&self, other: &Vector2) -> f64 {
+        self.cross(other).atan2(self.dot_product(other))
+    }
+
     pub fn normalize(&self) -> Vector2 {
         let mag = self.magnitude();
         Vector2::new(self.x / mag, self.y / mag)
     }
+
+    pub fn project_onto(&self, other: &Vector2) -> Vector2 {
+        let other_sq = other.dot_product(other);
+        if other_sq == 0.0 {
+            return Vector2::zero();
+        }
+
+        other.multiply(self.dot_product(other) / other_sq)
+    }
+
+    pub fn reject_from(&self, other: &Vector2) -> Vector2 {
+        self.subtract(&self.project_onto(other))
+    }
+
+    /// Reflects off a surface with the given normal, which is assumed to be unit length.
+    pub fn reflect(&self, normal: &Vector2) -> Vector2 {
+        self.subtract(&normal.multiply(2.0 * self.dot_product(normal)))
+    }
+
+    pub fn reflect_unnormalized(&self, normal: &Vector2) -> Vector2 {
+        self.reflect(&normal.normalize())
+    }
+
+    /// `t` is not clamped, so values outside `[0, 1]` extrapolate along the line.
+    pub fn lerp(&self, other: &Vector2, t: f64) -> Vector2 {
+        self.add(&other.subtract(self).multiply(t))
+    }
 }
 
 #[cfg(test)]
@@ -135,6 +233,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn component_multiply() {
+        let cases: Vec<VectorResultCase> = vec![
+            VectorResultCase {
+                a: Vector2::new(2, 3),
+                b: Vector2::new(5, 6),
+                expected: Vector2::new(10, 18),
+            },
+            VectorResultCase {
+                a: Vector2::new(-1.5, 4),
+                b: Vector2::new(2, 0),
+                expected: Vector2::new(-3, 0),
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.component_multiply(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn divide() {
+        struct DivideCase {
+            input: Vector2,
+            divisor: f64,
+            expected: Vector2,
+        }
+
+        let cases: Vec<DivideCase> = vec![
+            DivideCase {
+                input: Vector2::new(2, 4),
+                divisor: 2.0,
+                expected: Vector2::new(1, 2),
+            },
+            DivideCase {
+                input: Vector2::new(-7.5, 3),
+                divisor: 1.5,
+                expected: Vector2::new(-5, 2),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.divide(case.divisor);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
     #[test]
     fn dot_product() {
         let cases: Vec<ScalarResultCase> = vec![
@@ -157,6 +305,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn magnitude_squared() {
+        let inputs: Vec<Vector2> =
+            vec![Vector2::new(3, 4), Vector2::new(-2.5, 7.1), Vector2::zero()];
+
+        for input in inputs {
+            let magnitude = input.magnitude();
+
+            assert!(
+                input
+                    .magnitude_squared()
+                    .approx_eq_default(&(magnitude * magnitude))
+            );
+        }
+    }
+
+    #[test]
+    fn clamp_magnitude() {
+        struct ClampCase {
+            input: Vector2,
+            max: f64,
+            expected: Vector2,
+        }
+
+        let cases: Vec<ClampCase> = vec![
+            ClampCase {
+                input: Vector2::new(1, 2),
+                max: 5.0,
+                expected: Vector2::new(1, 2),
+            },
+            ClampCase {
+                input: Vector2::new(6, 8),
+                max: 5.0,
+                expected: Vector2::new(3, 4),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.clamp_magnitude(case.max);
+
+            assert!(result.approx_eq_default(&case.expected));
+            assert!(result.magnitude() <= case.max + 1e-9);
+        }
+    }
+
     #[test]
     fn angle_between() {
         let cases: Vec<ScalarResultCase> = vec![ScalarResultCase {
@@ -172,6 +365,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cross() {
+        let cases: Vec<ScalarResultCase> = vec![
+            ScalarResultCase {
+                a: Vector2::new(1, 0),
+                b: Vector2::new(0, 1),
+                expected: 1.0,
+            },
+            ScalarResultCase {
+                a: Vector2::new(2, 3),
+                b: Vector2::new(4, 6),
+                expected: 0.0,
+            },
+            ScalarResultCase {
+                a: Vector2::new(3, 1),
+                b: Vector2::new(-1, -2),
+                expected: -5.0,
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.cross(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn angle_between_degrees() {
+        let cases: Vec<ScalarResultCase> = vec![
+            ScalarResultCase {
+                a: Vector2::new(1, 0),
+                b: Vector2::new(0, 1),
+                expected: 90.0,
+            },
+            ScalarResultCase {
+                a: Vector2::new(1, 0),
+                b: Vector2::new(-3, 0),
+                expected: 180.0,
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.angle_between_degrees(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn signed_angle() {
+        let cases: Vec<ScalarResultCase> = vec![
+            ScalarResultCase {
+                a: Vector2::new(1, 0),
+                b: Vector2::new(0, 1),
+                expected: PI / 2.0,
+            },
+            ScalarResultCase {
+                a: Vector2::new(1, 0),
+                b: Vector2::new(0, -1),
+                expected: -PI / 2.0,
+            },
+            ScalarResultCase {
+                a: Vector2::new(1, 0),
+                b: Vector2::new(-2, 0),
+                expected: PI,
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.signed_angle(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
     #[test]
     fn normalize() {
         struct NormalizeCase {
@@ -199,4 +468,127 @@ mod tests {
             assert!(result.approx_eq_default(&case.expected));
         }
     }
+
+    #[test]
+    fn project_and_reject() {
+        struct DecomposeCase {
+            input: Vector2,
+            onto: Vector2,
+            projection: Vector2,
+            rejection: Vector2,
+        }
+
+        let cases: Vec<DecomposeCase> = vec![DecomposeCase {
+            input: Vector2::new(3, 4),
+            onto: Vector2::new(2, 0),
+            projection: Vector2::new(3, 0),
+            rejection: Vector2::new(0, 4),
+        }];
+
+        for case in cases {
+            let projection = case.input.project_onto(&case.onto);
+            let rejection = case.input.reject_from(&case.onto);
+
+            assert!(projection.approx_eq_default(&case.projection));
+            assert!(rejection.approx_eq_default(&case.rejection));
+        }
+    }
+
+    #[test]
+    fn projection_plus_rejection_is_original() {
+        let input = Vector2::new(-1, 5);
+        let onto = Vector2::new(1, 1);
+
+        let rejection = input.reject_from(&onto);
+        let result = input.project_onto(&onto).add(&rejection);
+
+        assert!(result.approx_eq_default(&input));
+        assert!(rejection.dot_product(&onto).approx_eq_default(&0.0));
+    }
+
+    #[test]
+    fn project_onto_zero_vector() {
+        let input = Vector2::new(-1, 5);
+        let zero = Vector2::new(0, 0);
+
+        assert!(input.project_onto(&zero).approx_eq_default(&zero));
+        assert!(input.reject_from(&zero).approx_eq_default(&input));
+    }
+
+    #[test]
+    fn reflect() {
+        struct ReflectCase {
+            input: Vector2,
+            normal: Vector2,
+            expected: Vector2,
+        }
+
+        let cases: Vec<ReflectCase> = vec![
+            ReflectCase {
+                input: Vector2::new(1, -1),
+                normal: Vector2::new(0, 1),
+                expected: Vector2::new(1, 1),
+            },
+            ReflectCase {
+                input: Vector2::new(3, 2),
+                normal: Vector2::new(-1, 0),
+                expected: Vector2::new(-3, 2),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.reflect(&case.normal);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn reflect_unnormalized() {
+        let result = Vector2::new(1, -1).reflect_unnormalized(&Vector2::new(0, 5));
+
+        assert!(result.approx_eq_default(&Vector2::new(1, 1)));
+    }
+
+    #[test]
+    fn named_constants() {
+        assert!(Vector2::zero().approx_eq_default(&Vector2::new(0, 0)));
+        assert!(Vector2::one().approx_eq_default(&Vector2::new(1, 1)));
+        assert!(Vector2::unit_x().approx_eq_default(&Vector2::new(1, 0)));
+        assert!(Vector2::unit_y().approx_eq_default(&Vector2::new(0, 1)));
+
+        assert!(Vector2::unit_x().magnitude().approx_eq_default(&1.0));
+        assert!(Vector2::unit_y().magnitude().approx_eq_default(&1.0));
+    }
+
+    #[test]
+    fn component_accessors() {
+        let v = Vector2::new(3.5, -2);
+
+        assert_eq!(v.x(), 3.5);
+        assert_eq!(v.y(), -2.0);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Vector2::new(2, -4);
+        let b = Vector2::new(6, 4);
+
+        assert!(a.lerp(&b, 0.0).approx_eq_default(&a));
+        assert!(a.lerp(&b, 1.0).approx_eq_default(&b));
+        assert!(a.lerp(&b, 0.25).approx_eq_default(&Vector2::new(3, -2)));
+        assert!(a.lerp(&b, 2.0).approx_eq_default(&Vector2::new(10, 12)));
+    }
+
+    #[test]
+    fn distance() {
+        let result = Vector2::new(1, 2).distance(&Vector2::new(4, -2));
+
+        assert!(result.approx_eq_default(&5.0));
+        assert!(
+            Vector2::new(1, 2)
+                .distance_squared(&Vector2::new(4, -2))
+                .approx_eq_default(&25.0)
+        );
+    }
 }
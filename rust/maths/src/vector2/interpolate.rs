@@ -0,0 +1,8 @@
+use super::Vector2;
+use crate::interpolate::Interpolate;
+
+impl Interpolate for Vector2 {
+    fn lerp(&self, other: &Vector2, t: f64) -> Vector2 {
+        Vector2::lerp(self, other, t)
+    }
+}
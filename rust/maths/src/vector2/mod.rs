@@ -1,6 +1,8 @@
 mod approx_eq;
+mod conversion;
 mod core;
 mod display;
+mod interpolate;
 
 #[derive(PartialEq, Debug)]
 pub struct Vector2 {
@@ -7,4 +7,8 @@ impl ApproxEq for Vector3 {
             && self.y.approx_eq(&other.y, eps)
             && self.z.approx_eq(&other.z, eps)
     }
+
+    fn max_abs(&self) -> f64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
 }
@@ -0,0 +1,97 @@
+use super::Vector3;
+
+impl From<(f64, f64, f64)> for Vector3 {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Vector3 { x, y, z }
+    }
+}
+
+impl From<[f64; 3]> for Vector3 {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Vector3 { x, y, z }
+    }
+}
+
+impl Vector3 {
+    pub fn to_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// `theta` is the polar angle from +z in `[0, PI]`, `phi` the azimuth from +x towards +y.
+    pub fn from_spherical(radius: f64, theta: f64, phi: f64) -> Vector3 {
+        Vector3::new(
+            radius * theta.sin() * phi.cos(),
+            radius * theta.sin() * phi.sin(),
+            radius * theta.cos(),
+        )
+    }
+
+    /// Returns `(radius, theta, phi)` using the conventions of `from_spherical`, with `phi` in
+    /// `(-PI, PI]`. The zero vector gives all zeros.
+    pub fn to_spherical(&self) -> (f64, f64, f64) {
+        let radius = self.magnitude();
+        if radius == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        (
+            radius,
+            (self.z / radius).clamp(-1.0, 1.0).acos(),
+            self.y.atan2(self.x),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approx_eq::ApproxEq;
+    use crate::vector3::Vector3;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn from_tuple() {
+        let result = Vector3::from((3.5, -2.0, 7.25));
+
+        assert!(result.approx_eq_default(&Vector3::new(3.5, -2.0, 7.25)));
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let cases: Vec<Vector3> = vec![
+            Vector3::new(3, 4, 5),
+            Vector3::new(-2.5, 15, 0.1),
+            Vector3::new(0, 0, 0),
+        ];
+
+        for case in cases {
+            let result = Vector3::from(case.to_array());
+
+            assert_eq!(result, case);
+        }
+    }
+
+    #[test]
+    fn from_spherical() {
+        let result = Vector3::from_spherical(2.0, PI / 2.0, PI / 2.0);
+
+        assert!(result.approx_eq_default(&Vector3::new(0, 2, 0)));
+        assert!(Vector3::from_spherical(3.0, 0.0, 1.0).approx_eq_default(&Vector3::new(0, 0, 3)));
+    }
+
+    #[test]
+    fn spherical_round_trip() {
+        let cases: Vec<Vector3> = vec![
+            Vector3::new(1, 2, 3),
+            Vector3::new(-4, 0.5, -1),
+            Vector3::new(0, -2, 0),
+            Vector3::new(0, 0, 0),
+        ];
+
+        for case in cases {
+            let (radius, theta, phi) = case.to_spherical();
+            let result = Vector3::from_spherical(radius, theta, phi);
+
+            assert!(result.approx_eq_default(&case));
+        }
+    }
+}
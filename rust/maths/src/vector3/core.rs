@@ -1,8 +1,9 @@
 use crate::matrix;
-use crate::matrix::Matrix;
 
 use super::Vector3;
 
+const NORMALIZE_EPSILON: f64 = 1e-12;
+
 impl Vector3 {
     pub fn new<X: Into<f64>, Y: Into<f64>, Z: Into<f64>>(x: X, y: Y, z: Z) -> Vector3 {
         Vector3 {
@@ -12,6 +13,38 @@ impl Vector3 {
         }
     }
 
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    pub fn zero() -> Vector3 {
+        Vector3::new(0, 0, 0)
+    }
+
+    pub fn one() -> Vector3 {
+        Vector3::new(1, 1, 1)
+    }
+
+    pub fn unit_x() -> Vector3 {
+        Vector3::new(1, 0, 0)
+    }
+
+    pub fn unit_y() -> Vector3 {
+        Vector3::new(0, 1, 0)
+    }
+
+    pub fn unit_z() -> Vector3 {
+        Vector3::new(0, 0, 1)
+    }
+
     pub fn add(&self, other: &Vector3) -> Vector3 {
         Vector3 {
             x: self.x + other.x,
@@ -36,32 +69,123 @@ impl Vector3 {
         }
     }
 
+    pub fn component_multiply(&self, other: &Vector3) -> Vector3 {
+        Vector3 {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+
+    /// Follows float semantics, so dividing by zero yields infinite or NaN components.
+    pub fn divide(&self, divisor: f64) -> Vector3 {
+        Vector3 {
+            x: self.x / divisor,
+            y: self.y / divisor,
+            z: self.z / divisor,
+        }
+    }
+
     pub fn dot_product(&self, other: &Vector3) -> f64 {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
 
+    pub fn magnitude_squared(&self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
     pub fn magnitude(&self) -> f64 {
-        let x_sq = self.x.powf(2.0);
-        let y_sq = self.y.powf(2.0);
-        let z_sq = self.z.powf(2.0);
-        let sum_sq = x_sq + y_sq + z_sq;
-        sum_sq.sqrt()
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn clamp_magnitude(&self, max: f64) -> Vector3 {
+        let mag_sq = self.magnitude_squared();
+        if mag_sq <= max * max {
+            return Vector3::new(self.x, self.y, self.z);
+        }
+
+        self.multiply(max / mag_sq.sqrt())
     }
 
     pub fn angle_between(&self, other: &Vector3) -> f64 {
         let dot = self.dot_product(other);
         let mags = self.magnitude() * other.magnitude();
 
-        let cos_theta = dot / mags;
+        let cos_theta = (dot / mags).clamp(-1.0, 1.0);
         cos_theta.acos()
     }
 
+    pub fn angle_between_degrees(&self, other: &Vector3) -> f64 {
+        self.angle_between(other).to_degrees()
+    }
+
+    /// The zero vector has no direction, so normalizing it yields NaN components.
     pub fn normalize(&self) -> Vector3 {
         let mag = self.magnitude();
-        println!("Mag {}", mag);
         Vector3::new(self.x / mag, self.y / mag, self.z / mag)
     }
 
+    pub fn normalize_or_zero(&self) -> Vector3 {
+        if self.magnitude() < NORMALIZE_EPSILON {
+            return Vector3::zero();
+        }
+
+        self.normalize()
+    }
+
+    /// `t` is not clamped, so values outside `[0, 1]` extrapolate along the line.
+    pub fn lerp(&self, other: &Vector3, t: f64) -> Vector3 {
+        self.add(&other.subtract(self).multiply(t))
+    }
+
+    /// Lerp then normalize; cheaper than slerp but not constant angular speed. Blending
+    /// opposite directions at the midpoint gives zero.
+    pub fn nlerp(&self, other: &Vector3, t: f64) -> Vector3 {
+        self.lerp(other, t).normalize_or_zero()
+    }
+
+    pub fn project_onto(&self, other: &Vector3) -> Vector3 {
+        let other_sq = other.dot_product(other);
+        if other_sq == 0.0 {
+            return Vector3::zero();
+        }
+
+        other.multiply(self.dot_product(other) / other_sq)
+    }
+
+    pub fn reject_from(&self, other: &Vector3) -> Vector3 {
+        self.subtract(&self.project_onto(other))
+    }
+
+    /// Removes the component along the plane's normal, which is assumed to be unit length.
+    pub fn project_onto_plane(&self, normal: &Vector3) -> Vector3 {
+        self.subtract(&normal.multiply(self.dot_product(normal)))
+    }
+
+    /// Reflects off a surface with the given normal, which is assumed to be unit length.
+    pub fn reflect(&self, normal: &Vector3) -> Vector3 {
+        self.subtract(&normal.multiply(2.0 * self.dot_product(normal)))
+    }
+
+    pub fn reflect_unnormalized(&self, normal: &Vector3) -> Vector3 {
+        self.reflect(&normal.normalize())
+    }
+
+    /// Snell's law for a unit incident direction and a unit normal facing against it, where
+    /// `eta` is the ratio of refractive indices (from / to). `None` on total internal reflection.
+    pub fn refract(&self, normal: &Vector3, eta: f64) -> Option<Vector3> {
+        let cos_incident = -self.dot_product(normal);
+        let k = 1.0 - eta * eta * (1.0 - cos_incident * cos_incident);
+        if k < 0.0 {
+            return None;
+        }
+
+        Some(
+            self.multiply(eta)
+                .add(&normal.multiply(eta * cos_incident - k.sqrt())),
+        )
+    }
+
     pub fn cross_product(&self, other: &Vector3) -> Vector3 {
         let x = matrix!(
             rows: 2,
@@ -90,12 +214,45 @@ impl Vector3 {
         .expect("det z");
         Vector3 { x, y, z }
     }
+
+    pub fn scalar_triple(&self, b: &Vector3, c: &Vector3) -> f64 {
+        self.dot_product(&b.cross_product(c))
+    }
+
+    pub fn rotate_around_axis(&self, axis: &Vector3, radians: f64) -> Vector3 {
+        let k = axis.normalize();
+        let (sin, cos) = radians.sin_cos();
+
+        self.multiply(cos)
+            .add(&k.cross_product(self).multiply(sin))
+            .add(&k.multiply(k.dot_product(self) * (1.0 - cos)))
+    }
+
+    /// Returns `(n, t, b)` with `n` the normalized input and `n x t = b`. Crosses against the
+    /// axis of the smallest component so `t` never degenerates.
+    pub fn orthonormal_basis(&self) -> (Vector3, Vector3, Vector3) {
+        let n = self.normalize();
+        let (ax, ay, az) = (n.x.abs(), n.y.abs(), n.z.abs());
+        let axis = if ax <= ay && ax <= az {
+            Vector3::unit_x()
+        } else if ay <= az {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+
+        let t = n.cross_product(&axis).normalize();
+        let b = n.cross_product(&t);
+
+        (n, t, b)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::approx_eq::ApproxEq;
+    use std::f64::consts::PI;
 
     struct VectorResultCase {
         a: Vector3,
@@ -173,6 +330,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn component_multiply() {
+        let cases: Vec<VectorResultCase> = vec![
+            VectorResultCase {
+                a: Vector3::new(2, 3, 4),
+                b: Vector3::new(5, 6, 7),
+                expected: Vector3::new(10, 18, 28),
+            },
+            VectorResultCase {
+                a: Vector3::new(-1.5, 4, 2),
+                b: Vector3::new(2, 0, -0.5),
+                expected: Vector3::new(-3, 0, -1),
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.component_multiply(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn divide() {
+        struct DivideCase {
+            input: Vector3,
+            divisor: f64,
+            expected: Vector3,
+        }
+
+        let cases: Vec<DivideCase> = vec![
+            DivideCase {
+                input: Vector3::new(2, 4, 6),
+                divisor: 2.0,
+                expected: Vector3::new(1, 2, 3),
+            },
+            DivideCase {
+                input: Vector3::new(-7.5, 3, 0),
+                divisor: 1.5,
+                expected: Vector3::new(-5, 2, 0),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.divide(case.divisor);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
     #[test]
     fn dot_product() {
         let cases: Vec<ScalarResultCase> = vec![
@@ -195,6 +402,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn magnitude_squared() {
+        let inputs: Vec<Vector3> = vec![
+            Vector3::new(1, 2, 2),
+            Vector3::new(-2.5, 7.1, 0.3),
+            Vector3::zero(),
+        ];
+
+        for input in inputs {
+            let magnitude = input.magnitude();
+
+            assert!(
+                input
+                    .magnitude_squared()
+                    .approx_eq_default(&(magnitude * magnitude))
+            );
+        }
+    }
+
+    #[test]
+    fn clamp_magnitude() {
+        struct ClampCase {
+            input: Vector3,
+            max: f64,
+            expected: Vector3,
+        }
+
+        let cases: Vec<ClampCase> = vec![
+            ClampCase {
+                input: Vector3::new(1, 2, 2),
+                max: 5.0,
+                expected: Vector3::new(1, 2, 2),
+            },
+            ClampCase {
+                input: Vector3::new(2, 4, 4),
+                max: 3.0,
+                expected: Vector3::new(1, 2, 2),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.clamp_magnitude(case.max);
+
+            assert!(result.approx_eq_default(&case.expected));
+            assert!(result.magnitude() <= case.max + 1e-9);
+        }
+    }
+
     #[test]
     fn angle_between() {
         let cases: Vec<ScalarResultCase> = vec![ScalarResultCase {
@@ -210,6 +465,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn angle_between_degrees() {
+        let cases: Vec<ScalarResultCase> = vec![
+            ScalarResultCase {
+                a: Vector3::new(1, 0, 0),
+                b: Vector3::new(0, 0, 4),
+                expected: 90.0,
+            },
+            ScalarResultCase {
+                a: Vector3::new(0, 1, 0),
+                b: Vector3::new(0, -3, 0),
+                expected: 180.0,
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.angle_between_degrees(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
     #[test]
     fn cross_product() {
         let cases: Vec<VectorResultCase> = vec![
@@ -232,6 +509,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn scalar_triple() {
+        struct TripleCase {
+            a: Vector3,
+            b: Vector3,
+            c: Vector3,
+            expected: f64,
+        }
+
+        let cases: Vec<TripleCase> = vec![
+            TripleCase {
+                a: Vector3::new(1, 0, 0),
+                b: Vector3::new(0, 1, 0),
+                c: Vector3::new(0, 0, 1),
+                expected: 1.0,
+            },
+            TripleCase {
+                a: Vector3::new(0, 1, 0),
+                b: Vector3::new(1, 0, 0),
+                c: Vector3::new(0, 0, 1),
+                expected: -1.0,
+            },
+            TripleCase {
+                a: Vector3::new(1, 2, 0),
+                b: Vector3::new(3, -1, 0),
+                c: Vector3::new(-2, 5, 0),
+                expected: 0.0,
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.scalar_triple(&case.b, &case.c);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn rotate_around_axis() {
+        struct RotateCase {
+            input: Vector3,
+            axis: Vector3,
+            radians: f64,
+            expected: Vector3,
+        }
+
+        let cases: Vec<RotateCase> = vec![
+            RotateCase {
+                input: Vector3::new(1, 0, 0),
+                axis: Vector3::new(0, 0, 1),
+                radians: PI / 2.0,
+                expected: Vector3::new(0, 1, 0),
+            },
+            RotateCase {
+                input: Vector3::new(1, 2, 3),
+                axis: Vector3::new(0, 5, 0),
+                radians: PI,
+                expected: Vector3::new(-1, 2, -3),
+            },
+            RotateCase {
+                input: Vector3::new(1, 0, 0),
+                axis: Vector3::new(1, 1, 1),
+                radians: 2.0 * PI / 3.0,
+                expected: Vector3::new(0, 1, 0),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.rotate_around_axis(&case.axis, case.radians);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
     #[test]
     fn normalize() {
         struct NormalizeCase {
@@ -255,4 +606,277 @@ mod tests {
             assert!(result.approx_eq_default(&case.expected));
         }
     }
+
+    #[test]
+    fn project_and_reject() {
+        struct DecomposeCase {
+            input: Vector3,
+            onto: Vector3,
+            projection: Vector3,
+            rejection: Vector3,
+        }
+
+        let cases: Vec<DecomposeCase> = vec![DecomposeCase {
+            input: Vector3::new(3, 4, 5),
+            onto: Vector3::new(0, 0, 2),
+            projection: Vector3::new(0, 0, 5),
+            rejection: Vector3::new(3, 4, 0),
+        }];
+
+        for case in cases {
+            let projection = case.input.project_onto(&case.onto);
+            let rejection = case.input.reject_from(&case.onto);
+
+            assert!(projection.approx_eq_default(&case.projection));
+            assert!(rejection.approx_eq_default(&case.rejection));
+        }
+    }
+
+    #[test]
+    fn projection_plus_rejection_is_original() {
+        let input = Vector3::new(2, -1, 4);
+        let onto = Vector3::new(1, 1, 0);
+
+        let rejection = input.reject_from(&onto);
+        let result = input.project_onto(&onto).add(&rejection);
+
+        assert!(result.approx_eq_default(&input));
+        assert!(rejection.dot_product(&onto).approx_eq_default(&0.0));
+    }
+
+    #[test]
+    fn project_onto_zero_vector() {
+        let input = Vector3::new(2, -1, 4);
+        let zero = Vector3::new(0, 0, 0);
+
+        assert!(input.project_onto(&zero).approx_eq_default(&zero));
+        assert!(input.reject_from(&zero).approx_eq_default(&input));
+    }
+
+    #[test]
+    fn reflect() {
+        struct ReflectCase {
+            input: Vector3,
+            normal: Vector3,
+            expected: Vector3,
+        }
+
+        let cases: Vec<ReflectCase> = vec![
+            ReflectCase {
+                input: Vector3::new(1, -1, 2),
+                normal: Vector3::new(0, 1, 0),
+                expected: Vector3::new(1, 1, 2),
+            },
+            ReflectCase {
+                input: Vector3::new(3, 2, -4),
+                normal: Vector3::new(0, 0, 1),
+                expected: Vector3::new(3, 2, 4),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.reflect(&case.normal);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn reflect_unnormalized() {
+        let result = Vector3::new(1, -1, 2).reflect_unnormalized(&Vector3::new(0, 5, 0));
+
+        assert!(result.approx_eq_default(&Vector3::new(1, 1, 2)));
+    }
+
+    #[test]
+    fn named_constants() {
+        assert!(Vector3::zero().approx_eq_default(&Vector3::new(0, 0, 0)));
+        assert!(Vector3::one().approx_eq_default(&Vector3::new(1, 1, 1)));
+        assert!(Vector3::unit_x().approx_eq_default(&Vector3::new(1, 0, 0)));
+        assert!(Vector3::unit_y().approx_eq_default(&Vector3::new(0, 1, 0)));
+        assert!(Vector3::unit_z().approx_eq_default(&Vector3::new(0, 0, 1)));
+
+        assert!(Vector3::unit_x().magnitude().approx_eq_default(&1.0));
+        assert!(Vector3::unit_y().magnitude().approx_eq_default(&1.0));
+        assert!(Vector3::unit_z().magnitude().approx_eq_default(&1.0));
+    }
+
+    #[test]
+    fn component_accessors() {
+        let v = Vector3::new(3.5, -2, 7);
+
+        assert_eq!(v.x(), 3.5);
+        assert_eq!(v.y(), -2.0);
+        assert_eq!(v.z(), 7.0);
+    }
+
+    #[test]
+    fn normalize_is_unit_length() {
+        let inputs: Vec<Vector3> = vec![
+            Vector3::new(1, 2, 2),
+            Vector3::new(-4.5, 0.1, 30),
+            Vector3::new(0, 0, -0.001),
+        ];
+
+        for input in inputs {
+            let result = input.normalize();
+
+            assert!(result.magnitude().approx_eq_default(&1.0));
+        }
+    }
+
+    #[test]
+    fn normalize_or_zero() {
+        struct NormalizeCase {
+            input: Vector3,
+            expected: Vector3,
+        }
+
+        let cases: Vec<NormalizeCase> = vec![
+            NormalizeCase {
+                input: Vector3::new(2, -3, 6),
+                expected: Vector3::new(2.0 / 7.0, -3.0 / 7.0, 6.0 / 7.0),
+            },
+            NormalizeCase {
+                input: Vector3::zero(),
+                expected: Vector3::zero(),
+            },
+            NormalizeCase {
+                input: Vector3::new(1e-15, 0, -1e-15),
+                expected: Vector3::zero(),
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.normalize_or_zero();
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn orthonormal_basis() {
+        let cases: Vec<Vector3> = vec![
+            Vector3::new(0, 0, 1),
+            Vector3::new(3, -2, 0.5),
+            Vector3::new(-1, -1, -1),
+            Vector3::new(0, 7, 0),
+        ];
+
+        for case in cases {
+            let (n, t, b) = case.orthonormal_basis();
+
+            assert!(n.approx_eq_default(&case.normalize()));
+            for v in [&n, &t, &b] {
+                assert!(v.magnitude().approx_eq_default(&1.0));
+            }
+            assert!(n.dot_product(&t).approx_eq_default(&0.0));
+            assert!(n.dot_product(&b).approx_eq_default(&0.0));
+            assert!(t.dot_product(&b).approx_eq_default(&0.0));
+            assert!(n.scalar_triple(&t, &b).approx_eq_default(&1.0));
+        }
+    }
+
+    #[test]
+    fn project_onto_plane() {
+        let cases: Vec<VectorResultCase> = vec![
+            VectorResultCase {
+                a: Vector3::new(1, 1, 1),
+                b: Vector3::new(0, 1, 0),
+                expected: Vector3::new(1, 0, 1),
+            },
+            VectorResultCase {
+                a: Vector3::new(3, -2, 5),
+                b: Vector3::new(0, 0, 1),
+                expected: Vector3::new(3, -2, 0),
+            },
+        ];
+
+        for case in cases {
+            let result = case.a.project_onto_plane(&case.b);
+
+            assert!(result.approx_eq_default(&case.expected));
+        }
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Vector3::new(2, -4, 0);
+        let b = Vector3::new(6, 4, -8);
+
+        assert!(a.lerp(&b, 0.0).approx_eq_default(&a));
+        assert!(a.lerp(&b, 1.0).approx_eq_default(&b));
+        assert!(a.lerp(&b, 0.25).approx_eq_default(&Vector3::new(3, -2, -2)));
+        assert!(
+            a.lerp(&b, 2.0)
+                .approx_eq_default(&Vector3::new(10, 12, -16))
+        );
+    }
+
+    #[test]
+    fn nlerp() {
+        let a = Vector3::new(1, 0, 0);
+        let b = Vector3::new(0, 0.6, 0.8);
+
+        for t in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+            let result = a.nlerp(&b, t);
+
+            assert!(result.magnitude().approx_eq_default(&1.0));
+        }
+        assert!(a.nlerp(&b, 0.0).approx_eq_default(&a));
+        assert!(a.nlerp(&b, 1.0).approx_eq_default(&b));
+        assert!(
+            a.nlerp(&b, 0.5)
+                .approx_eq_default(&Vector3::new(0.5, 0.3, 0.4).normalize())
+        );
+    }
+
+    #[test]
+    fn refract() {
+        struct RefractCase {
+            input: Vector3,
+            normal: Vector3,
+            eta: f64,
+            expected: Option<Vector3>,
+        }
+
+        let s = 1.0 / 2f64.sqrt();
+        let sin_t = s / 1.5;
+        let cases: Vec<RefractCase> = vec![
+            RefractCase {
+                input: Vector3::new(0.6, -0.8, 0),
+                normal: Vector3::new(0, 1, 0),
+                eta: 1.0,
+                expected: Some(Vector3::new(0.6, -0.8, 0)),
+            },
+            RefractCase {
+                input: Vector3::new(0, 0, -1),
+                normal: Vector3::new(0, 0, 1),
+                eta: 1.0 / 1.5,
+                expected: Some(Vector3::new(0, 0, -1)),
+            },
+            RefractCase {
+                input: Vector3::new(s, -s, 0),
+                normal: Vector3::new(0, 1, 0),
+                eta: 1.0 / 1.5,
+                expected: Some(Vector3::new(sin_t, -(1.0 - sin_t * sin_t).sqrt(), 0)),
+            },
+            RefractCase {
+                input: Vector3::new(3f64.sqrt() / 2.0, -0.5, 0),
+                normal: Vector3::new(0, 1, 0),
+                eta: 1.5,
+                expected: None,
+            },
+        ];
+
+        for case in cases {
+            let result = case.input.refract(&case.normal, case.eta);
+
+            match (result, case.expected) {
+                (Some(result), Some(expected)) => assert!(result.approx_eq_default(&expected)),
+                (None, None) => {}
+                _ => panic!("refraction of {} did not match", case.input),
+            }
+        }
+    }
 }
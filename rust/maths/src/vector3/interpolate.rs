@@ -0,0 +1,43 @@
+use super::Vector3;
+use crate::interpolate::Interpolate;
+
+impl Interpolate for Vector3 {
+    fn lerp(&self, other: &Vector3, t: f64) -> Vector3 {
+        Vector3::lerp(self, other, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+    use crate::vector2::Vector2;
+
+    fn halfway<T: Interpolate>(a: &T, b: &T) -> T {
+        a.lerp(b, 0.5)
+    }
+
+    #[test]
+    fn generic_interpolation() {
+        let scalar = halfway(&1.0, &4.0);
+        let vector2 = halfway(&Vector2::new(0, 2), &Vector2::new(4, 0));
+        let vector3 = halfway(&Vector3::new(0, 2, -4), &Vector3::new(4, 0, 4));
+
+        assert!(scalar.approx_eq_default(&2.5));
+        assert!(vector2.approx_eq_default(&Vector2::new(2, 1)));
+        assert!(vector3.approx_eq_default(&Vector3::new(2, 1, 0)));
+    }
+
+    #[test]
+    fn smoothstep_interp() {
+        let a = Vector3::new(0, 0, 0);
+        let b = Vector3::new(4, 8, -4);
+
+        assert!(a.smoothstep_interp(&b, 0.0).approx_eq_default(&a));
+        assert!(a.smoothstep_interp(&b, 1.0).approx_eq_default(&b));
+        assert!(
+            a.smoothstep_interp(&b, 0.25)
+                .approx_eq_default(&Vector3::new(0.625, 1.25, -0.625))
+        );
+    }
+}
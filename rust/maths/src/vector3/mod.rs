@@ -1,6 +1,10 @@
 mod approx_eq;
+mod conversion;
 mod core;
 mod display;
+mod interpolate;
+#[cfg(feature = "random")]
+mod random;
 
 #[derive(PartialEq, Debug)]
 pub struct Vector3 {
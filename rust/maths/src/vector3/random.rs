@@ -0,0 +1,31 @@
+use super::Vector3;
+use crate::random::Rng;
+use std::f64::consts::PI;
+
+impl Vector3 {
+    /// Uniformly distributed over the unit sphere and fully determined by `seed`.
+    pub fn random_unit(seed: u64) -> Vector3 {
+        let mut rng = Rng::new(seed);
+        let z = 2.0 * rng.next_f64() - 1.0;
+        let angle = 2.0 * PI * rng.next_f64();
+        let radius = (1.0 - z * z).sqrt();
+
+        Vector3::new(radius * angle.cos(), radius * angle.sin(), z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn random_unit() {
+        for seed in 0..20 {
+            let v = Vector3::random_unit(seed);
+
+            assert!(v.magnitude().approx_eq_default(&1.0));
+            assert_eq!(v, Vector3::random_unit(seed));
+        }
+    }
+}
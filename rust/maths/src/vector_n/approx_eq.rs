@@ -0,0 +1,12 @@
+use super::VectorN;
+use crate::approx_eq::ApproxEq;
+
+impl ApproxEq for VectorN {
+    fn approx_eq(&self, other: &VectorN, eps: f64) -> bool {
+        self.0.approx_eq(&other.0, eps)
+    }
+
+    fn max_abs(&self) -> f64 {
+        self.0.max_abs()
+    }
+}
@@ -0,0 +1,115 @@
+use super::{VectorError, VectorN};
+
+impl VectorN {
+    pub fn zeros(dimension: usize) -> VectorN {
+        VectorN(vec![0.0; dimension])
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.0.len()
+    }
+
+    fn check_dimension(&self, other: &VectorN) -> Result<(), VectorError> {
+        if self.dimension() != other.dimension() {
+            return Err(VectorError::DimensionMismatch(
+                self.dimension(),
+                other.dimension(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn add(&self, other: &VectorN) -> Result<VectorN, VectorError> {
+        self.check_dimension(other)?;
+
+        Ok(VectorN(
+            self.0
+                .iter()
+                .zip(other.0.iter())
+                .map(|(a, b)| a + b)
+                .collect(),
+        ))
+    }
+
+    pub fn subtract(&self, other: &VectorN) -> Result<VectorN, VectorError> {
+        self.check_dimension(other)?;
+
+        Ok(VectorN(
+            self.0
+                .iter()
+                .zip(other.0.iter())
+                .map(|(a, b)| a - b)
+                .collect(),
+        ))
+    }
+
+    pub fn scale(&self, multiplier: f64) -> VectorN {
+        VectorN(self.0.iter().map(|v| v * multiplier).collect())
+    }
+
+    pub fn dot_product(&self, other: &VectorN) -> Result<f64, VectorError> {
+        self.check_dimension(other)?;
+
+        Ok(self.0.iter().zip(other.0.iter()).map(|(a, b)| a * b).sum())
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.0.iter().map(|v| v * v).sum::<f64>().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_eq::ApproxEq;
+
+    #[test]
+    fn addition_and_subtraction() {
+        let a = VectorN(vec![1.0, 2.0, 3.0, 4.0]);
+        let b = VectorN(vec![0.5, -2.0, 1.0, 6.0]);
+
+        assert!(
+            a.add(&b)
+                .unwrap()
+                .approx_eq_default(&VectorN(vec![1.5, 0.0, 4.0, 10.0]))
+        );
+        assert!(
+            a.subtract(&b)
+                .unwrap()
+                .approx_eq_default(&VectorN(vec![0.5, 4.0, 2.0, -2.0]))
+        );
+    }
+
+    #[test]
+    fn dot_product() {
+        let a = VectorN(vec![1.0, 2.0, 3.0, 4.0]);
+        let b = VectorN(vec![4.0, -3.0, 2.0, 1.0]);
+
+        assert_eq!(a.dot_product(&b), Ok(8.0));
+    }
+
+    #[test]
+    fn scale_and_magnitude() {
+        let v = VectorN(vec![1.0, 1.0, 1.0, 1.0]);
+
+        assert!(v.magnitude().approx_eq_default(&2.0));
+        assert!(v.scale(3.0).magnitude().approx_eq_default(&6.0));
+        assert_eq!(VectorN::zeros(3).magnitude(), 0.0);
+    }
+
+    #[test]
+    fn dimension_mismatch() {
+        let a = VectorN(vec![1.0, 2.0, 3.0, 4.0]);
+        let b = VectorN(vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(a.add(&b), Err(VectorError::DimensionMismatch(4, 3)));
+        assert_eq!(a.dot_product(&b), Err(VectorError::DimensionMismatch(4, 3)));
+        assert!(!a.approx_eq_default(&b));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", VectorN(vec![1.0, -2.5, 3.0])), "(1, -2.5, 3)");
+    }
+}
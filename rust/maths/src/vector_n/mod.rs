@@ -0,0 +1,11 @@
+mod approx_eq;
+mod core;
+mod display;
+
+#[derive(PartialEq, Debug)]
+pub struct VectorN(pub Vec<f64>);
+
+#[derive(Debug, PartialEq)]
+pub enum VectorError {
+    DimensionMismatch(usize, usize),
+}